@@ -1,3 +1,9 @@
+pub mod polygon;
+pub mod scalar;
 pub mod vectors;
 #[cfg(test)]
+mod polygon_tests;
+#[cfg(test)]
+mod scalar_tests;
+#[cfg(test)]
 mod vectors_tests;
@@ -0,0 +1,61 @@
+use crate::math::vectors::Vec2;
+
+/// Signed area and centroid of a simple polygon. The area is positive for
+/// counter-clockwise winding in a y-up frame, the centroid is zero for degenerate polygons.
+pub fn area_and_centroid(points: &[Vec2]) -> (f32, Vec2) {
+    let mut area = 0.0;
+    let mut centroid = Vec2::zeros();
+
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let cross = a.cross(&b);
+
+        area += cross;
+        centroid += (a + b) * cross;
+    }
+
+    area *= 0.5;
+    if area == 0.0 {
+        return (0.0, Vec2::zeros());
+    }
+
+    (area, centroid / (6.0 * area))
+}
+
+/// Keeps the part of the polygon on the side of the line through `origin` that `normal` points to.
+pub fn clip_half_plane(points: &[Vec2], origin: Vec2, normal: Vec2) -> Vec<Vec2> {
+    let mut clipped = Vec::with_capacity(points.len() + 1);
+
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let (da, db) = ((a - origin).dot(&normal), (b - origin).dot(&normal));
+
+        if da >= 0.0 {
+            clipped.push(a);
+        }
+
+        if (da >= 0.0) != (db >= 0.0) {
+            clipped.push(a + (b - a) * (da / (da - db)));
+        }
+    }
+
+    clipped
+}
+
+/// Intersection of a polygon with a convex polygon of either winding.
+pub fn clip_convex(points: &[Vec2], convex: &[Vec2]) -> Vec<Vec2> {
+    let (area, _) = area_and_centroid(convex);
+    let inward = if area >= 0.0 { 1.0 } else { -1.0 };
+
+    let mut clipped = points.to_vec();
+    for i in 0..convex.len() {
+        if clipped.is_empty() {
+            break;
+        }
+
+        let (a, b) = (convex[i], convex[(i + 1) % convex.len()]);
+        clipped = clip_half_plane(&clipped, a, (b - a).perp() * inward);
+    }
+
+    clipped
+}
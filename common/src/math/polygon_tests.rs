@@ -0,0 +1,41 @@
+use super::polygon::{area_and_centroid, clip_convex, clip_half_plane};
+use super::vectors::Vec2;
+
+fn square(size: f32) -> Vec<Vec2> {
+    vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(size, 0.0),
+        Vec2::new(size, size),
+        Vec2::new(0.0, size),
+    ]
+}
+
+#[test]
+fn test_polygon_area_and_centroid() {
+    let (area, centroid) = area_and_centroid(&square(2.0));
+    assert_eq!(area, 4.0);
+    assert_eq!(centroid.x, 1.0);
+    assert_eq!(centroid.y, 1.0);
+
+    let reversed: Vec<Vec2> = square(2.0).into_iter().rev().collect();
+    assert_eq!(area_and_centroid(&reversed).0, -4.0);
+}
+
+#[test]
+fn test_polygon_clip_half_plane() {
+    let clipped = clip_half_plane(&square(2.0), Vec2::new(0.0, 0.5), Vec2::new(0.0, 1.0));
+    let (area, centroid) = area_and_centroid(&clipped);
+
+    assert!((area - 3.0).abs() < 1e-5);
+    assert!((centroid.y - 1.25).abs() < 1e-5);
+}
+
+#[test]
+fn test_polygon_clip_convex() {
+    let offset: Vec<Vec2> = square(2.0).into_iter().map(|p| p + Vec2::new(1.0, 1.0)).collect();
+    let (area, _) = area_and_centroid(&clip_convex(&square(2.0), &offset));
+    assert!((area - 1.0).abs() < 1e-5);
+
+    let far: Vec<Vec2> = square(1.0).into_iter().map(|p| p + Vec2::new(5.0, 5.0)).collect();
+    assert!(clip_convex(&square(2.0), &far).is_empty());
+}
@@ -0,0 +1,37 @@
+use std::f64::consts::FRAC_PI_2;
+
+/// Sine and cosine of `angle`. With the `consistent-math` feature this uses `soft_sin_cos`,
+/// otherwise the platform's implementation, which may differ in the last bits between targets.
+pub fn sin_cos(angle: f32) -> (f32, f32) {
+    if cfg!(feature = "consistent-math") {
+        soft_sin_cos(angle)
+    } else {
+        angle.sin_cos()
+    }
+}
+
+/// Sine and cosine built only from IEEE basic arithmetic, so every platform gets the same bits.
+/// The angle is reduced to the nearest quarter turn in double precision and both functions are
+/// evaluated there with their Taylor series, which are exact to well below `f32` precision.
+pub fn soft_sin_cos(angle: f32) -> (f32, f32) {
+    if !angle.is_finite() {
+        return (f32::NAN, f32::NAN);
+    }
+
+    let x = angle as f64;
+    let quarter = (x / FRAC_PI_2).round();
+    let r = x - quarter * FRAC_PI_2;
+    let r2 = r * r;
+
+    let sin = r * (1.0 - r2 / 6.0 * (1.0 - r2 / 20.0 * (1.0 - r2 / 42.0 * (1.0 - r2 / 72.0 * (1.0 - r2 / 110.0)))));
+    let cos = 1.0 - r2 / 2.0 * (1.0 - r2 / 12.0 * (1.0 - r2 / 30.0 * (1.0 - r2 / 56.0 * (1.0 - r2 / 90.0 * (1.0 - r2 / 132.0)))));
+
+    let (sin, cos) = match (quarter as i64).rem_euclid(4) {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    };
+
+    (sin as f32, cos as f32)
+}
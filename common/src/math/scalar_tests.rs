@@ -0,0 +1,23 @@
+use super::scalar::soft_sin_cos;
+
+#[test]
+fn test_soft_sin_cos_matches_std() {
+    for i in -2000..=2000 {
+        let angle = i as f32 * 0.01;
+        let (sin, cos) = soft_sin_cos(angle);
+
+        assert!((sin - angle.sin()).abs() <= 1e-6, "sin({})", angle);
+        assert!((cos - angle.cos()).abs() <= 1e-6, "cos({})", angle);
+    }
+}
+
+#[test]
+fn test_soft_sin_cos_quarter_turns() {
+    let (sin, cos) = soft_sin_cos(0.0);
+    assert_eq!((sin, cos), (0.0, 1.0));
+
+    let (sin, cos) = soft_sin_cos(std::f32::consts::PI);
+    assert!(sin.abs() < 1e-6 && cos == -1.0);
+
+    assert!(soft_sin_cos(f32::INFINITY).0.is_nan());
+}
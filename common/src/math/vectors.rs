@@ -1,12 +1,16 @@
+use crate::math::scalar;
+use std::fmt;
 use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec2 {
     pub x: f32,
     pub y: f32,
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vec3 {
     pub x: f32,
     pub y: f32,
@@ -27,7 +31,7 @@ impl Vec2 {
     }
 
     pub fn unit(&self) -> Self {
-        self.clone() / self.length()
+        *self / self.length()
     }
 
     pub fn dist(&self, other: &Vec2) -> f32 {
@@ -37,6 +41,44 @@ impl Vec2 {
     pub fn dot(&self, other: &Vec2) -> f32 {
         self.x * other.x + self.y * other.y
     }
+
+    /// Z component of the 3D cross product of the two vectors.
+    pub fn cross(&self, other: &Vec2) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Vector rotated by 90 degrees counter-clockwise.
+    pub fn perp(&self) -> Self {
+        Self { x: -self.y, y: self.x }
+    }
+
+    pub fn rotate(&self, angle: f32) -> Self {
+        let (sin, cos) = scalar::sin_cos(angle);
+        Self {
+            x: self.x * cos - self.y * sin,
+            y: self.x * sin + self.y * cos,
+        }
+    }
+
+    /// Same direction, shortened to `max` if it is longer.
+    pub fn clamp_length(&self, max: f32) -> Self {
+        let length = self.length();
+        if length > max {
+            *self * (max / length)
+        } else {
+            *self
+        }
+    }
+}
+
+/// Written as `(x, y)`, passing any precision on to both components.
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*})", p, self.x, p, self.y),
+            None => write!(f, "({}, {})", self.x, self.y),
+        }
+    }
 }
 
 impl Add for Vec2 {
@@ -135,3 +177,12 @@ impl Vec3 {
         Self { x, y, z }
     }
 }
+
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match f.precision() {
+            Some(p) => write!(f, "({:.*}, {:.*}, {:.*})", p, self.x, p, self.y, p, self.z),
+            None => write!(f, "({}, {}, {})", self.x, self.y, self.z),
+        }
+    }
+}
@@ -31,3 +31,50 @@ fn test_vec2_element_wise_with_0() {
     assert_eq!(result.x, 0.5);
     assert_eq!(result.y, 0.0);
 }
+
+#[test]
+fn test_vec2_cross() {
+    let v1 = Vec2::new(1.0, 0.0);
+    let v2 = Vec2::new(0.0, 1.0);
+    assert_eq!(v1.cross(&v2), 1.0);
+    assert_eq!(v2.cross(&v1), -1.0);
+}
+
+#[test]
+fn test_vec2_rotate() {
+    let v = Vec2::new(1.0, 0.0).rotate(std::f32::consts::FRAC_PI_2);
+    assert!(v.x.abs() < 1e-6);
+    assert!((v.y - 1.0).abs() < 1e-6);
+
+    let p = Vec2::new(2.0, 3.0).perp();
+    assert_eq!(p.x, -3.0);
+    assert_eq!(p.y, 2.0);
+}
+
+#[test]
+fn test_vec2_clamp_length() {
+    let v = Vec2::new(3.0, 4.0).clamp_length(2.5);
+    assert!((v.x - 1.5).abs() < 1e-6);
+    assert!((v.y - 2.0).abs() < 1e-6);
+
+    assert_eq!(Vec2::new(3.0, 4.0).clamp_length(f32::INFINITY).x, 3.0);
+}
+
+#[test]
+fn test_vec2_display() {
+    let v = Vec2::new(1.5, -2.0);
+
+    assert_eq!(v.to_string(), "(1.5, -2)");
+    assert_eq!(format!("{:.2}", v), "(1.50, -2.00)");
+    assert_eq!(Vec2::default(), Vec2::zeros());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_vec2_serde_round_trip() {
+    let v = Vec2::new(3.0, 4.5);
+    let text = ron::to_string(&v).unwrap();
+
+    assert_eq!(text, "(x:3.0,y:4.5)");
+    assert_eq!(ron::from_str::<Vec2>(&text).unwrap(), v);
+}
@@ -0,0 +1,84 @@
+//! Narrow phase tests for every shape pair, hitting and missing, and the contact response.
+
+use common::math::vectors::Vec2;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use physics_playground::collisions::colliders::{CircleBB, RectangleBB};
+use physics_playground::collisions::response::handle_collision;
+use physics_playground::collisions::{BoundBox, Collider};
+use physics_playground::entity::circle_entity::CircleEntity;
+use physics_playground::entity::rectangle_entity::RectangleEntity;
+use physics_playground::world::EntityRef;
+use std::cell::RefCell;
+use std::hint::black_box;
+use std::rc::Rc;
+
+/// Builds two bodies whose colliders overlap.
+type Pair = fn() -> (EntityRef, EntityRef);
+
+fn circle(radius: f32) -> Collider {
+    Collider { rel_pos: Vec2::zeros(), bound_box: BoundBox::Circle(CircleBB { radius }) }
+}
+
+fn rectangle(width: f32, height: f32) -> Collider {
+    Collider { rel_pos: Vec2::zeros(), bound_box: BoundBox::Rectangle(RectangleBB { width, height }) }
+}
+
+fn detect_collision(c: &mut Criterion) {
+    let mut group = c.benchmark_group("detect_collision");
+    let shapes = [("circle", circle(10.0)), ("rectangle", rectangle(20.0, 20.0))];
+    // Offsets of the second collider that overlap the first and that are well clear of it
+    let cases = [("hit", Vec2::new(12.0, 5.0)), ("miss", Vec2::new(100.0, 50.0))];
+
+    for (name_a, a) in &shapes {
+        for (name_b, b) in &shapes {
+            for (case, offset) in cases {
+                let (pos_a, pos_b) = (Vec2::new(100.0, 100.0), Vec2::new(100.0, 100.0) + offset);
+
+                group.bench_function(format!("{}-{}/{}", name_a, name_b, case), |bench| {
+                    bench.iter(|| black_box(a).detect_collision(black_box(&pos_a), black_box(b), black_box(&pos_b)))
+                });
+            }
+        }
+    }
+
+    group.finish();
+}
+
+fn contact_response(c: &mut Criterion) {
+    let mut group = c.benchmark_group("handle_collision");
+    let pairs: [(&str, Pair); 2] = [
+        ("dynamic-dynamic", || {
+            let mut a = CircleEntity::new(100.0, 100.0);
+            a.velocity = Vec2::new(50.0, 0.0);
+            (Rc::new(RefCell::new(a)), Rc::new(RefCell::new(CircleEntity::new(115.0, 100.0))))
+        }),
+        ("static-dynamic", || {
+            let mut ball = CircleEntity::new(100.0, 95.0);
+            ball.velocity = Vec2::new(0.0, 50.0);
+            (Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 100.0, 200.0, 20.0))), Rc::new(RefCell::new(ball)))
+        }),
+    ];
+
+    for (name, pair) in pairs {
+        group.bench_function(name, |bench| {
+            // Fresh bodies every time, a resolved pair is already separating and takes the early exit
+            bench.iter_batched(
+                || {
+                    let (a, b) = pair();
+                    let collision = {
+                        let (ea, eb) = (a.borrow(), b.borrow());
+                        ea.get_collider().detect_collision(&ea.get_position(), eb.get_collider(), &eb.get_position())
+                    };
+                    (a, b, collision.expect("the bodies should overlap"))
+                },
+                |(a, b, collision)| black_box(handle_collision(a.borrow_mut(), b.borrow_mut(), &collision)),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, detect_collision, contact_response);
+criterion_main!(benches);
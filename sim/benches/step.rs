@@ -0,0 +1,100 @@
+//! Full `World::step` on standard scenes at growing body counts. Every measured step starts from
+//! a freshly built scene, so results do not depend on how many iterations criterion runs.
+
+use common::math::vectors::Vec2;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, SamplingMode};
+use physics_playground::effectors::forces::uniform_gravity::UniformGravity;
+use physics_playground::entity::circle_entity::CircleEntity;
+use physics_playground::entity::rectangle_entity::RectangleEntity;
+use physics_playground::world::World;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DELTA: f32 = 1.0 / 60.0;
+const BODY_COUNTS: [usize; 3] = [100, 1_000, 10_000];
+const RADIUS: f32 = 10.0;
+const MARGIN: f32 = 100.0;
+
+/// Builds a scene with the given number of bodies.
+type Scene = fn(usize) -> World;
+
+/// Empty box with a floor and walls, big enough for `n` bodies spaced `spacing` apart, under gravity.
+fn room(n: usize, spacing: f32) -> (World, usize, f32) {
+    let columns = (n as f32).sqrt().ceil() as usize;
+    let size = columns as f32 * spacing * 2.0 + 2.0 * MARGIN;
+
+    let mut world = World::new(size, size);
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, size - MARGIN, size, 20.0))));
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(MARGIN - 20.0, 0.0, 20.0, size))));
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(size - MARGIN, 0.0, 20.0, size))));
+    world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 200.0))));
+
+    (world, columns, size)
+}
+
+/// Balls packed slightly closer than their diameter in a heap on the floor, every neighbour in contact.
+fn pile(n: usize) -> World {
+    let spacing = RADIUS * 1.9;
+    let (mut world, columns, size) = room(n, spacing);
+    let floor = size - MARGIN - RADIUS;
+
+    for i in 0..n {
+        let (column, row) = (i % columns, i / columns);
+        let x = MARGIN + RADIUS + column as f32 * spacing + if row % 2 == 0 { 0.0 } else { RADIUS };
+        world.add_entity(Rc::new(RefCell::new(CircleEntity::new(x, floor - row as f32 * spacing))));
+    }
+
+    world
+}
+
+/// Balls spread across the room at staggered heights, falling with few contacts.
+fn rain(n: usize) -> World {
+    let spacing = RADIUS * 4.0;
+    let (mut world, columns, _) = room(n, spacing);
+
+    for i in 0..n {
+        let (column, row) = (i % columns, i / columns);
+        let x = MARGIN + RADIUS + column as f32 * spacing * 2.0 + (i * 7 % 11) as f32;
+        let y = MARGIN + row as f32 * spacing + (i * 13 % 17) as f32;
+        let mut ball = CircleEntity::new(x, y);
+        ball.velocity = Vec2::new(0.0, 100.0);
+        world.add_entity(Rc::new(RefCell::new(ball)));
+    }
+
+    world
+}
+
+/// Crates on a regular lattice with gaps between them, no contacts at all.
+fn grid(n: usize) -> World {
+    let spacing = RADIUS * 3.0;
+    let (mut world, columns, _) = room(n, spacing);
+
+    for i in 0..n {
+        let (column, row) = (i % columns, i / columns);
+        let (x, y) = (MARGIN + column as f32 * spacing, MARGIN + row as f32 * spacing);
+        world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(x, y, RADIUS * 2.0, RADIUS * 2.0))));
+    }
+
+    world
+}
+
+fn step(c: &mut Criterion) {
+    let scenes: [(&str, Scene); 3] = [("pile", pile), ("rain", rain), ("grid", grid)];
+
+    for (name, scene) in scenes {
+        let mut group = c.benchmark_group(format!("step/{}", name));
+        // Every pair is tested, the largest scenes take long enough per step that fewer samples do
+        group.sample_size(10).sampling_mode(SamplingMode::Flat);
+
+        for n in BODY_COUNTS {
+            group.bench_with_input(BenchmarkId::from_parameter(n), &n, |bench, &n| {
+                bench.iter_batched_ref(|| scene(n), |world| world.step(DELTA), BatchSize::LargeInput)
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, step);
+criterion_main!(benches);
@@ -0,0 +1,72 @@
+use super::{jitter, BodyState, Format, RowWriter, StepMetrics};
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn world() -> World {
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 900.0, 1000.0, 20.0))));
+    let ball = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(400.0, 400.0))));
+    *world.get_entity(ball).unwrap().borrow_mut().get_velocity_mut() = common::math::vectors::Vec2::new(3.0, -4.0);
+    world
+}
+
+fn written(format: Format, rows: &[BodyState]) -> String {
+    let mut out = vec![];
+    let mut writer = RowWriter::new(&mut out, format);
+    for row in rows {
+        writer.write(row).unwrap();
+    }
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_format_parses_names() {
+    assert_eq!("csv".parse(), Ok(Format::Csv));
+    assert_eq!("jsonl".parse(), Ok(Format::JsonLines));
+    assert!("xml".parse::<Format>().is_err());
+}
+
+#[test]
+fn test_rows_write_as_csv_and_json_lines() {
+    let rows = BodyState::all(&world());
+
+    let csv = written(Format::Csv, &rows);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], "handle,x,y,vx,vy,angle,angular_velocity");
+    assert_eq!(lines[2], "1,400,400,3,-4,0,0");
+
+    let json = written(Format::JsonLines, &rows);
+    let second: serde_json::Value = serde_json::from_str(json.lines().nth(1).unwrap()).unwrap();
+    assert_eq!(second["handle"], 1);
+    assert_eq!(second["vy"], -4.0);
+}
+
+#[test]
+fn test_metrics_sum_dynamic_entities() {
+    let world = world();
+    let ball = world.entities.values().nth(1).unwrap().borrow();
+    let metrics = StepMetrics::measure(&world, 3, 0.05);
+
+    assert_eq!(metrics.entities, 2);
+    assert_eq!(metrics.kinetic_energy, 0.5 * ball.get_mass() * 25.0);
+    assert_eq!((metrics.momentum_x, metrics.momentum_y), (3.0 * ball.get_mass(), -4.0 * ball.get_mass()));
+}
+
+#[test]
+fn test_jitter_is_seeded_and_leaves_static_entities() {
+    let (mut a, mut b, mut c) = (world(), world(), world());
+    jitter(&mut a, 7, 0.5);
+    jitter(&mut b, 7, 0.5);
+    jitter(&mut c, 8, 0.5);
+
+    assert_eq!(BodyState::all(&a), BodyState::all(&b));
+    assert_ne!(BodyState::all(&a), BodyState::all(&c));
+    assert_eq!(BodyState::all(&a)[0], BodyState::all(&world())[0]);
+
+    let moved = &BodyState::all(&a)[1];
+    assert!((moved.x - 400.0).abs() <= 0.5 && (moved.y - 400.0).abs() <= 0.5);
+}
@@ -0,0 +1,198 @@
+pub mod trajectory;
+
+#[cfg(test)]
+mod batch_tests;
+#[cfg(test)]
+mod trajectory_tests;
+
+use crate::entity::EntityType;
+use crate::world::World;
+use serde::Serialize;
+use std::io;
+use std::io::Write;
+use std::str::FromStr;
+
+/// How batch output is written: CSV with a header line, or one JSON object per line.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Format {
+    Csv,
+    JsonLines,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "csv" => Ok(Format::Csv),
+            "json" | "jsonl" => Ok(Format::JsonLines),
+            _ => Err(format!("unknown format {}, expected csv or jsonl", s)),
+        }
+    }
+}
+
+/// A record that can be written as a CSV line as well as JSON.
+pub trait Row: Serialize {
+    fn csv_header() -> &'static str;
+    fn csv_fields(&self) -> String;
+}
+
+/// Writes rows of one kind in the chosen format, starting CSV output with its header.
+pub struct RowWriter<W: Write> {
+    out: W,
+    format: Format,
+    started: bool,
+}
+
+impl<W: Write> RowWriter<W> {
+    pub fn new(out: W, format: Format) -> Self {
+        Self { out, format, started: false }
+    }
+
+    pub fn write<R: Row>(&mut self, row: &R) -> io::Result<()> {
+        match self.format {
+            Format::Csv => {
+                if !self.started {
+                    writeln!(self.out, "{}", R::csv_header())?;
+                }
+                writeln!(self.out, "{}", row.csv_fields())?;
+            }
+            Format::JsonLines => {
+                serde_json::to_writer(&mut self.out, row)?;
+                writeln!(self.out)?;
+            }
+        }
+
+        self.started = true;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+/// Totals over the whole world after a step, see `Diagnostics` for what each covers.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct StepMetrics {
+    pub step: u64,
+    pub time: f32,
+    pub entities: usize,
+    pub joints: usize,
+    pub kinetic_energy: f32,
+    pub potential_energy: f32,
+    pub joint_energy: f32,
+    pub momentum_x: f32,
+    pub momentum_y: f32,
+    pub angular_momentum: f32,
+    pub collision_loss: f32,
+}
+
+impl StepMetrics {
+    pub fn measure(world: &World, step: u64, time: f32) -> Self {
+        let diagnostics = world.diagnostics();
+
+        Self {
+            step,
+            time,
+            entities: world.entities.len(),
+            joints: world.joints.len(),
+            kinetic_energy: diagnostics.kinetic_energy,
+            potential_energy: diagnostics.potential_energy,
+            joint_energy: diagnostics.joint_energy,
+            momentum_x: diagnostics.linear_momentum.x,
+            momentum_y: diagnostics.linear_momentum.y,
+            angular_momentum: diagnostics.angular_momentum,
+            collision_loss: diagnostics.collision_loss,
+        }
+    }
+}
+
+impl Row for StepMetrics {
+    fn csv_header() -> &'static str {
+        "step,time,entities,joints,kinetic_energy,potential_energy,joint_energy,momentum_x,momentum_y,angular_momentum,collision_loss"
+    }
+
+    fn csv_fields(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{},{}",
+            self.step, self.time, self.entities, self.joints, self.kinetic_energy, self.potential_energy,
+            self.joint_energy, self.momentum_x, self.momentum_y, self.angular_momentum, self.collision_loss
+        )
+    }
+}
+
+/// One entity's state, as written out at the end of a run.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct BodyState {
+    pub handle: u32,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub angle: f32,
+    pub angular_velocity: f32,
+}
+
+impl BodyState {
+    /// Every entity in the world, in handle order.
+    pub fn all(world: &World) -> Vec<Self> {
+        world.entities.iter()
+            .map(|(handle, entity)| {
+                let entity = entity.borrow();
+                let (position, velocity) = (entity.get_position(), entity.get_velocity());
+
+                Self {
+                    handle: handle.0,
+                    x: position.x,
+                    y: position.y,
+                    vx: velocity.x,
+                    vy: velocity.y,
+                    angle: entity.get_angle(),
+                    angular_velocity: entity.get_angular_velocity(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl Row for BodyState {
+    fn csv_header() -> &'static str {
+        "handle,x,y,vx,vy,angle,angular_velocity"
+    }
+
+    fn csv_fields(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.handle, self.x, self.y, self.vx, self.vy, self.angle, self.angular_velocity
+        )
+    }
+}
+
+/// Moves every dynamic entity by up to `amount` in each axis, by amounts drawn from `seed`. Runs with
+/// different seeds start from nearby states, so a sweep over seeds shows how sensitive a scene is.
+pub fn jitter(world: &mut World, seed: u64, amount: f32) {
+    let mut state = seed;
+
+    for entity in world.entities.values() {
+        let mut entity = entity.borrow_mut();
+        if let EntityType::Static = entity.get_type() {
+            continue;
+        }
+
+        let position = entity.get_position_mut();
+        position.x += amount * unit_random(&mut state);
+        position.y += amount * unit_random(&mut state);
+    }
+}
+
+/// SplitMix64, mapped to `[-1, 1]`.
+fn unit_random(state: &mut u64) -> f32 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^= z >> 31;
+
+    (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+}
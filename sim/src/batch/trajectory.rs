@@ -0,0 +1,131 @@
+use crate::batch::{Row, RowWriter};
+use crate::effectors::selector::Selector;
+use crate::world::{EntityHandle, World};
+use serde::Serialize;
+use std::io;
+use std::io::Write;
+use std::str::FromStr;
+
+/// Which entities a trajectory follows.
+#[derive(Clone)]
+pub enum Track {
+    Handles(Vec<EntityHandle>),
+    Select(Selector),
+}
+
+impl FromStr for Track {
+    type Err = String;
+
+    /// `all`, `tag:<name>` or a comma separated list of handles.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Track::Select(Selector::AllDynamic));
+        }
+        if let Some(tag) = s.strip_prefix("tag:") {
+            return Ok(Track::Select(Selector::Tag(tag.to_string())));
+        }
+
+        s.split(',')
+            .map(|handle| handle.trim().parse().map(EntityHandle))
+            .collect::<Result<_, _>>()
+            .map(Track::Handles)
+            .map_err(|_| format!("invalid track {}, expected all, tag:<name> or handles like 1,2", s))
+    }
+}
+
+/// One tracked entity at one step.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TrajectorySample {
+    pub step: u64,
+    pub time: f32,
+    pub handle: u32,
+    /// The tag the entity is tracked by, otherwise its first tag, empty when it has none.
+    pub tag: String,
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+    pub angle: f32,
+    pub kinetic_energy: f32,
+}
+
+impl Row for TrajectorySample {
+    fn csv_header() -> &'static str {
+        "step,time,handle,tag,x,y,vx,vy,angle,kinetic_energy"
+    }
+
+    fn csv_fields(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.step, self.time, self.handle, csv_text(&self.tag),
+            self.x, self.y, self.vx, self.vy, self.angle, self.kinetic_energy
+        )
+    }
+}
+
+/// Quotes text containing separators or quotes, doubling the quotes inside.
+fn csv_text(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Writes the tracked entities' state every `every` steps.
+pub struct TrajectoryRecorder<W: Write> {
+    track: Track,
+    every: u64,
+    writer: RowWriter<W>,
+}
+
+impl<W: Write> TrajectoryRecorder<W> {
+    pub fn new(track: Track, every: u64, writer: RowWriter<W>) -> Self {
+        Self { track, every: every.max(1), writer }
+    }
+
+    /// Samples the world if `step` falls on the interval. Entities that have left the world are skipped.
+    pub fn sample(&mut self, world: &World, step: u64, time: f32) -> io::Result<()> {
+        if !step.is_multiple_of(self.every) {
+            return Ok(());
+        }
+
+        let tracked: Vec<_> = match &self.track {
+            Track::Handles(handles) => handles.iter()
+                .filter_map(|handle| world.get_entity(*handle).map(|e| (*handle, e)))
+                .collect(),
+            Track::Select(selector) => world.entities.iter()
+                .filter(|(_, e)| selector.matches(&*e.borrow()))
+                .map(|(handle, e)| (*handle, e))
+                .collect(),
+        };
+
+        for (handle, entity) in tracked {
+            let entity = entity.borrow();
+            let (position, velocity) = (entity.get_position(), entity.get_velocity());
+            let tag = match &self.track {
+                Track::Select(Selector::Tag(tag)) => tag.clone(),
+                _ => entity.get_tags().first().cloned().unwrap_or_default(),
+            };
+
+            self.writer.write(&TrajectorySample {
+                step,
+                time,
+                handle: handle.0,
+                tag,
+                x: position.x,
+                y: position.y,
+                vx: velocity.x,
+                vy: velocity.y,
+                angle: entity.get_angle(),
+                kinetic_energy: entity.get_kinetic_energy(),
+            })?;
+        }
+
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
@@ -0,0 +1,81 @@
+use super::trajectory::{Track, TrajectoryRecorder};
+use super::{Format, RowWriter};
+use crate::effectors::selector::Selector;
+use crate::entity::circle_entity::CircleEntity;
+use crate::world::{EntityHandle, World};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn world() -> World {
+    let mut world = World::new(1000.0, 1000.0);
+    for (i, tag) in ["planet", "moon", "moon, small"].into_iter().enumerate() {
+        let mut e = CircleEntity::new(100.0 * (i + 1) as f32, 500.0);
+        e.tags.push(tag.to_string());
+        world.add_entity(Rc::new(RefCell::new(e)));
+    }
+    world
+}
+
+fn recorded(track: Track, every: u64, format: Format, steps: u64) -> String {
+    let mut world = world();
+    let mut out = vec![];
+    let mut recorder = TrajectoryRecorder::new(track, every, RowWriter::new(&mut out, format));
+
+    for step in 1..=steps {
+        world.step(0.01);
+        recorder.sample(&world, step, step as f32 * 0.01).unwrap();
+    }
+    drop(recorder);
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn test_track_parses_handles_and_tags() {
+    assert!(matches!("3, 5".parse(), Ok(Track::Handles(h)) if h == vec![EntityHandle(3), EntityHandle(5)]));
+    assert!(matches!("tag:moon".parse(), Ok(Track::Select(Selector::Tag(t))) if t == "moon"));
+    assert!(matches!("all".parse(), Ok(Track::Select(Selector::AllDynamic))));
+    assert!("moon".parse::<Track>().is_err());
+}
+
+#[test]
+fn test_samples_tracked_entities_every_k_steps() {
+    let csv = recorded(Track::Handles(vec![EntityHandle(0), EntityHandle(9)]), 5, Format::Csv, 20);
+    let lines: Vec<&str> = csv.lines().collect();
+
+    assert_eq!(lines[0], "step,time,handle,tag,x,y,vx,vy,angle,kinetic_energy");
+    assert_eq!(lines.len(), 5);
+    assert!(lines[1..].iter().zip([5, 10, 15, 20]).all(|(line, step)| line.starts_with(&format!("{},", step))));
+    assert!(lines[1..].iter().all(|line| line.split(',').nth(3) == Some("planet")));
+}
+
+#[test]
+fn test_tag_tracking_writes_json_lines() {
+    let jsonl = recorded(Track::Select(Selector::Tag("moon".to_string())), 1, Format::JsonLines, 2);
+    let samples: Vec<serde_json::Value> = jsonl.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+
+    assert_eq!(samples.len(), 2);
+    assert_eq!(samples[0]["handle"], 1);
+    assert_eq!(samples[1]["step"], 2);
+}
+
+#[test]
+fn test_tag_tracking_keys_samples_by_the_tracked_tag() {
+    let mut world = World::new(1000.0, 1000.0);
+    let mut moon = CircleEntity::new(100.0, 500.0);
+    moon.tags = vec!["spawned".to_string(), "moon".to_string()];
+    world.add_entity(Rc::new(RefCell::new(moon)));
+
+    let mut out = vec![];
+    let mut recorder = TrajectoryRecorder::new("tag:moon".parse().unwrap(), 1, RowWriter::new(&mut out, Format::JsonLines));
+    recorder.sample(&world, 1, 0.01).unwrap();
+    drop(recorder);
+
+    let sample: serde_json::Value = serde_json::from_slice(&out).unwrap();
+    assert_eq!(sample["tag"], "moon");
+}
+
+#[test]
+fn test_csv_quotes_tags_with_commas() {
+    let csv = recorded(Track::Handles(vec![EntityHandle(2)]), 1, Format::Csv, 1);
+    assert!(csv.lines().nth(1).unwrap().contains(",\"moon, small\","));
+}
@@ -0,0 +1,160 @@
+//! Runs a scene for a fixed number of steps without a window, writing per-step metrics and, when asked,
+//! the final state of every entity. Meant for batch jobs and parameter sweeps where no window can open.
+
+use physics_playground::batch::trajectory::{Track, TrajectoryRecorder};
+use physics_playground::batch::{jitter, BodyState, Format, RowWriter, StepMetrics};
+use physics_playground::effectors::selector::Selector;
+use physics_playground::world::World;
+use std::fs::File;
+use std::io;
+use std::io::{BufWriter, Write};
+use std::process::ExitCode;
+use std::time::Instant;
+
+const USAGE: &str = "usage: physics-cli --scene <file> [--steps <n>] [--dt <seconds>] [--seed <n>] \
+[--iterations <n>] [--format csv|jsonl] [--metrics <file>] [--state <file>] \
+[--trajectory <file> [--track all|tag:<name>|<handle>,...] [--every <k>]]";
+/// Largest distance `--seed` moves a body from where the scene puts it.
+const SEED_JITTER: f32 = 0.5;
+
+struct Options {
+    scene: String,
+    steps: u64,
+    dt: f32,
+    seed: Option<u64>,
+    iterations: Option<usize>,
+    format: Format,
+    /// Metrics go to stdout unless given a file.
+    metrics: Option<String>,
+    /// The final state and trajectories are only written when given a file, so stdout holds a single table.
+    state: Option<String>,
+    trajectory: Option<String>,
+    track: Track,
+    every: u64,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut scene = None;
+        let mut options = Options {
+            scene: String::new(),
+            steps: 600,
+            dt: 1.0 / 60.0,
+            seed: None,
+            iterations: None,
+            format: Format::Csv,
+            metrics: None,
+            state: None,
+            trajectory: None,
+            track: Track::Select(Selector::AllDynamic),
+            every: 1,
+        };
+
+        while let Some(flag) = args.next() {
+            let value = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+
+            match flag.as_str() {
+                "--scene" => scene = Some(value),
+                "--steps" => options.steps = parse(&flag, &value)?,
+                "--dt" => options.dt = parse(&flag, &value)?,
+                "--seed" => options.seed = Some(parse(&flag, &value)?),
+                "--iterations" => options.iterations = Some(parse(&flag, &value)?),
+                "--format" => options.format = value.parse()?,
+                "--metrics" => options.metrics = Some(value),
+                "--state" => options.state = Some(value),
+                "--trajectory" => options.trajectory = Some(value),
+                "--track" => options.track = value.parse()?,
+                "--every" => options.every = parse(&flag, &value)?,
+                _ => return Err(format!("unknown flag {}", flag)),
+            }
+        }
+
+        if options.dt <= 0.0 {
+            return Err("--dt must be positive".to_string());
+        }
+        if options.every == 0 {
+            return Err("--every must be at least 1".to_string());
+        }
+        options.scene = scene.ok_or("--scene is required")?;
+        Ok(options)
+    }
+}
+
+fn parse<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("invalid value {} for {}", value, flag))
+}
+
+fn metrics_output(path: &Option<String>) -> io::Result<Box<dyn Write>> {
+    Ok(match path {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    })
+}
+
+fn run(options: &Options) -> io::Result<()> {
+    let mut world = World::load_scene(&options.scene)?;
+    if let Some(seed) = options.seed {
+        jitter(&mut world, seed, SEED_JITTER);
+    }
+    if let Some(iterations) = options.iterations {
+        world.set_solver_iterations(iterations);
+    }
+
+    let started = Instant::now();
+    let mut metrics = RowWriter::new(metrics_output(&options.metrics)?, options.format);
+    let mut trajectory = match &options.trajectory {
+        Some(path) => {
+            let writer = RowWriter::new(BufWriter::new(File::create(path)?), options.format);
+            Some(TrajectoryRecorder::new(options.track.clone(), options.every, writer))
+        }
+        None => None,
+    };
+
+    for step in 1..=options.steps {
+        world.step(options.dt);
+
+        let time = step as f32 * options.dt;
+        metrics.write(&StepMetrics::measure(&world, step, time))?;
+        if let Some(trajectory) = &mut trajectory {
+            trajectory.sample(&world, step, time)?;
+        }
+    }
+    metrics.flush()?;
+    if let Some(trajectory) = &mut trajectory {
+        trajectory.flush()?;
+    }
+
+    if let Some(path) = &options.state {
+        let mut state = RowWriter::new(BufWriter::new(File::create(path)?), options.format);
+        for body in BodyState::all(&world) {
+            state.write(&body)?;
+        }
+        state.flush()?;
+    }
+
+    eprintln!(
+        "ran {} steps in {:.3}s, state {:016x}",
+        options.steps,
+        started.elapsed().as_secs_f32(),
+        world.state_hash()
+    );
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("physics-cli: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
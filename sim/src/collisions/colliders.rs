@@ -1,7 +1,9 @@
+#[derive(Clone)]
 pub struct  CircleBB{
     pub radius: f32
 }
 
+#[derive(Clone)]
 pub struct RectangleBB {
     pub width: f32,
     pub height: f32
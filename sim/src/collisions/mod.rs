@@ -1,3 +1,4 @@
+use std::f32::consts::PI;
 use common::math::vectors::Vec2;
 use crate::collisions::colliders::{CircleBB, RectangleBB};
 
@@ -5,11 +6,149 @@ pub mod detection;
 pub mod colliders;
 pub mod response;
 
+const CIRCLE_SEGMENTS: usize = 24;
+
+#[derive(Clone)]
 pub struct Collider {
     pub rel_pos: Vec2,
     pub bound_box: BoundBox
 }
 
+impl Collider {
+    /// Geometric centre of the collider for an entity located at `pos`.
+    pub fn centroid(&self, pos: &Vec2) -> Vec2 {
+        let origin = *pos + self.rel_pos;
+
+        match &self.bound_box {
+            BoundBox::Circle(_) => origin,
+            BoundBox::Rectangle(r) => origin + Vec2::new(r.width / 2.0, r.height / 2.0),
+        }
+    }
+
+    /// Whether the world space `point` lies inside the collider of an entity located at `pos`.
+    pub fn contains_point(&self, pos: &Vec2, point: &Vec2) -> bool {
+        let origin = *pos + self.rel_pos;
+
+        match &self.bound_box {
+            BoundBox::Circle(c) => origin.dist(point) <= c.radius,
+            BoundBox::Rectangle(r) => {
+                point.x >= origin.x && point.x <= origin.x + r.width
+                    && point.y >= origin.y && point.y <= origin.y + r.height
+            }
+        }
+    }
+
+    /// Width of the collider's silhouette seen along the unit vector `direction`.
+    pub fn cross_section(&self, direction: &Vec2) -> f32 {
+        match &self.bound_box {
+            BoundBox::Circle(c) => 2.0 * c.radius,
+            BoundBox::Rectangle(r) => r.width * direction.y.abs() + r.height * direction.x.abs(),
+        }
+    }
+
+    pub fn area(&self) -> f32 {
+        match &self.bound_box {
+            BoundBox::Circle(c) => PI * c.radius * c.radius,
+            BoundBox::Rectangle(r) => r.width * r.height,
+        }
+    }
+
+    /// Outline of the collider for an entity located at `pos`, circles are approximated by a regular polygon.
+    pub fn to_polygon(&self, pos: &Vec2) -> Vec<Vec2> {
+        let origin = *pos + self.rel_pos;
+
+        match &self.bound_box {
+            BoundBox::Circle(c) => (0..CIRCLE_SEGMENTS)
+                .map(|i| {
+                    let angle = 2.0 * PI * i as f32 / CIRCLE_SEGMENTS as f32;
+                    origin + Vec2::new(c.radius, 0.0).rotate(angle)
+                })
+                .collect(),
+            BoundBox::Rectangle(r) => vec![
+                origin,
+                origin + Vec2::new(r.width, 0.0),
+                origin + Vec2::new(r.width, r.height),
+                origin + Vec2::new(0.0, r.height),
+            ],
+        }
+    }
+
+    /// Distance along the unit `direction` from `origin` at which the ray enters the collider
+    /// of an entity located at `pos`, if it does so within `max_distance`. Zero when `origin` is inside.
+    pub fn ray_cast(&self, pos: &Vec2, origin: Vec2, direction: Vec2, max_distance: f32) -> Option<f32> {
+        let collider_origin = *pos + self.rel_pos;
+
+        let t = match &self.bound_box {
+            BoundBox::Circle(c) => {
+                let m = origin - collider_origin;
+                let b = m.dot(&direction);
+                let c = m.dot(&m) - c.radius * c.radius;
+                if c <= 0.0 {
+                    return Some(0.0);
+                }
+
+                let discriminant = b * b - c;
+                if b > 0.0 || discriminant < 0.0 {
+                    return None;
+                }
+                -b - discriminant.sqrt()
+            }
+            BoundBox::Rectangle(r) => {
+                let max = collider_origin + Vec2::new(r.width, r.height);
+                let (mut t_min, mut t_max) = (0.0f32, max_distance);
+
+                for (o, d, lo, hi) in [
+                    (origin.x, direction.x, collider_origin.x, max.x),
+                    (origin.y, direction.y, collider_origin.y, max.y),
+                ] {
+                    if d.abs() < f32::EPSILON {
+                        if o < lo || o > hi {
+                            return None;
+                        }
+                        continue;
+                    }
+
+                    let (t1, t2) = ((lo - o) / d, (hi - o) / d);
+                    t_min = t_min.max(t1.min(t2));
+                    t_max = t_max.min(t1.max(t2));
+                    if t_min > t_max {
+                        return None;
+                    }
+                }
+                t_min
+            }
+        };
+
+        (t <= max_distance).then_some(t)
+    }
+
+    /// Copy of the collider grown by `margin` on every side.
+    pub fn expanded(&self, margin: f32) -> Collider {
+        match &self.bound_box {
+            BoundBox::Circle(c) => Collider {
+                rel_pos: self.rel_pos,
+                bound_box: BoundBox::Circle(CircleBB { radius: c.radius + margin }),
+            },
+            BoundBox::Rectangle(r) => Collider {
+                rel_pos: self.rel_pos - Vec2::new(margin, margin),
+                bound_box: BoundBox::Rectangle(RectangleBB {
+                    width: r.width + 2.0 * margin,
+                    height: r.height + 2.0 * margin,
+                }),
+            },
+        }
+    }
+
+    /// Moment of inertia about the centroid for a uniform body of the given mass.
+    pub fn moment_of_inertia(&self, mass: f32) -> f32 {
+        match &self.bound_box {
+            BoundBox::Circle(c) => 0.5 * mass * c.radius * c.radius,
+            BoundBox::Rectangle(r) => mass * (r.width * r.width + r.height * r.height) / 12.0,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub enum BoundBox {
     Circle(CircleBB),
     Rectangle(RectangleBB)
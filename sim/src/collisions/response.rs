@@ -4,7 +4,8 @@ use crate::entity::{Entity, EntityType};
 
 const RESTITUTION: f32 = 0.7;
 
-pub fn handle_collision(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn Entity>, collision: &Collision) {
+/// Separates the pair and bounces them apart, returning the kinetic energy the bounce took out.
+pub fn handle_collision(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn Entity>, collision: &Collision) -> f32 {
     let obj1_mass = obj1.get_mass();
     let obj2_mass = obj2.get_mass();
 
@@ -27,11 +28,15 @@ pub fn handle_collision(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn Entit
     };
     
     if matches!(obj1.get_type(), EntityType::Dynamic) || matches!(obj2.get_type(), EntityType::Dynamic) {
-        apply_velocity_response(obj1, obj2, collision);
+        apply_velocity_response(obj1, obj2, collision)
+    } else {
+        0.0
     }
 }
 
-fn apply_velocity_response(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn Entity>, collision: &Collision) {
+/// Returns the kinetic energy lost to restitution below one. The impulse is along the normal only,
+/// there is no friction, so that is all the energy the response takes out.
+fn apply_velocity_response(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn Entity>, collision: &Collision) -> f32 {
     let inv_mass_a = match obj1.get_type() {
         EntityType::Dynamic => 1.0 / obj1.get_mass(),
         EntityType::Static => 0.0
@@ -44,14 +49,14 @@ fn apply_velocity_response(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn En
 
     let total_inv_mass = inv_mass_a + inv_mass_b;
     if total_inv_mass == 0.0 {
-        return;
+        return 0.0;
     }
 
     let relative_velocity = obj2.get_velocity() - obj1.get_velocity();
     let velocity_along_normal = relative_velocity.dot(&collision.direction);
 
     if velocity_along_normal > 0.0 {
-        return;
+        return 0.0;
     }
 
     let impulse_magnitude = -(1.0 + RESTITUTION) * velocity_along_normal / total_inv_mass;
@@ -64,4 +69,6 @@ fn apply_velocity_response(mut obj1: RefMut<dyn Entity>, mut obj2: RefMut<dyn En
     if matches!(obj2.get_type(), EntityType::Dynamic) {
         *obj2.get_velocity_mut() += impulse * inv_mass_b;
     }
-}
\ No newline at end of file
+
+    (1.0 - RESTITUTION * RESTITUTION) * velocity_along_normal * velocity_along_normal / (2.0 * total_inv_mass)
+}
@@ -0,0 +1,136 @@
+use common::math::vectors::Vec2;
+
+/// Deepest subdivision, keeps coincident bodies from recursing forever.
+const MAX_DEPTH: usize = 24;
+
+struct Node {
+    center: Vec2,
+    half_size: f32,
+    mass: f32,
+    mass_center: Vec2,
+    /// Index of the single body stored in a leaf.
+    body: Option<usize>,
+    /// Index of the first of four consecutive children.
+    children: Option<usize>,
+}
+
+impl Node {
+    fn new(center: Vec2, half_size: f32) -> Self {
+        Self {
+            center,
+            half_size,
+            mass: 0.0,
+            mass_center: Vec2::zeros(),
+            body: None,
+            children: None,
+        }
+    }
+
+    fn quadrant(&self, pos: Vec2) -> usize {
+        let right = (pos.x >= self.center.x) as usize;
+        let bottom = (pos.y >= self.center.y) as usize;
+        right + 2 * bottom
+    }
+}
+
+/// Quadtree of point masses used to approximate far away groups of bodies by their centre of mass.
+pub(crate) struct QuadTree {
+    nodes: Vec<Node>,
+    positions: Vec<Vec2>,
+    masses: Vec<f32>,
+}
+
+impl QuadTree {
+    pub fn new(positions: Vec<Vec2>, masses: Vec<f32>) -> Self {
+        let (mut min, mut max) = (Vec2::new(f32::MAX, f32::MAX), Vec2::new(f32::MIN, f32::MIN));
+        for p in &positions {
+            min = Vec2::new(min.x.min(p.x), min.y.min(p.y));
+            max = Vec2::new(max.x.max(p.x), max.y.max(p.y));
+        }
+
+        let center = (min + max) * 0.5;
+        let half_size = f32::max(max.x - min.x, max.y - min.y) * 0.5 + 1.0;
+
+        let mut tree = Self {
+            nodes: vec![Node::new(center, half_size)],
+            positions,
+            masses,
+        };
+
+        for i in 0..tree.positions.len() {
+            tree.insert(0, i, 0);
+        }
+
+        tree
+    }
+
+    fn insert(&mut self, node: usize, body: usize, depth: usize) {
+        let (pos, mass) = (self.positions[body], self.masses[body]);
+
+        let n = &mut self.nodes[node];
+        let total = n.mass + mass;
+        n.mass_center = if total > 0.0 { (n.mass_center * n.mass + pos * mass) / total } else { pos };
+        n.mass = total;
+
+        if let Some(first) = n.children {
+            let child = first + n.quadrant(pos);
+            return self.insert(child, body, depth + 1);
+        }
+
+        let Some(existing) = n.body else {
+            n.body = Some(body);
+            return;
+        };
+
+        if depth >= MAX_DEPTH {
+            // Too close to tell apart, the leaf keeps only the aggregated mass
+            return;
+        }
+
+        let (center, half) = (n.center, n.half_size * 0.5);
+        n.body = None;
+
+        let first = self.nodes.len();
+        self.nodes[node].children = Some(first);
+
+        for (dx, dy) in [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)] {
+            self.nodes.push(Node::new(center + Vec2::new(dx, dy) * half, half));
+        }
+
+        // Push both bodies down without counting their mass twice
+        for b in [existing, body] {
+            let child = first + self.nodes[node].quadrant(self.positions[b]);
+            self.insert(child, b, depth + 1);
+        }
+    }
+
+    /// Gravitational acceleration per unit `g` acting on `body`, with Plummer softening.
+    pub fn acceleration(&self, body: usize, theta: f32, softening: f32) -> Vec2 {
+        let mut acc = Vec2::zeros();
+        let mut stack = vec![0];
+        let pos = self.positions[body];
+
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if node.mass == 0.0 || node.body == Some(body) {
+                continue;
+            }
+
+            let d = node.mass_center - pos;
+            let dist_sq = d.dot(&d);
+            let width = node.half_size * 2.0;
+
+            match node.children {
+                Some(first) if width * width >= theta * theta * dist_sq => {
+                    stack.extend(first..first + 4);
+                }
+                _ => {
+                    let soft = dist_sq + softening * softening;
+                    acc += d * (node.mass / (soft * soft.sqrt()));
+                }
+            }
+        }
+
+        acc
+    }
+}
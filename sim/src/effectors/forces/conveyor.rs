@@ -0,0 +1,73 @@
+use std::any::Any;
+use std::rc::Rc;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::world::{EntityHandle, EntityMap, EntityRef};
+
+/// Gap under which a body still counts as resting on the surface,
+/// collisions push bodies fully out of it every step.
+const CONTACT_MARGIN: f32 = 1.0;
+
+/// Turns an entity into a conveyor belt: bodies touching it get the tangential
+/// part of their velocity set to `speed`, relative to the surface's own velocity. Positive speeds carry bodies on top of the surface to the right.
+#[derive(Clone)]
+pub struct Conveyor {
+    pub selector: Selector,
+    pub surface: EntityHandle,
+    pub speed: f32,
+}
+
+impl Conveyor {
+    pub fn new(surface: EntityHandle, speed: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            surface,
+            speed,
+        }
+    }
+}
+
+impl Effector for Conveyor {
+    fn update(&mut self, targets: &[EntityRef], entities: &EntityMap, _delta: f32) {
+        let Some(surface) = entities.get(&self.surface) else {
+            return;
+        };
+        let (collider, position, velocity) = {
+            let surface = surface.borrow();
+            (surface.get_collider().expanded(CONTACT_MARGIN), surface.get_position(), surface.get_velocity())
+        };
+        // A dynamic surface can be among the targets, but never carries itself
+        let targets: Vec<EntityRef> = targets.iter().filter(|e| !Rc::ptr_eq(e, surface)).cloned().collect();
+
+        for_each_dynamic(&targets, |entity| {
+            let contact = collider.detect_collision(&position, entity.get_collider(), &entity.get_position());
+
+            if let Some(contact) = contact {
+                let tangent = contact.direction.perp();
+                let v = entity.get_velocity();
+                let belt_speed = self.speed + velocity.dot(&tangent);
+                *entity.get_velocity_mut() = v + tangent * (belt_speed - v.dot(&tangent));
+            }
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,47 @@
+use super::conveyor::Conveyor;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::entity::Entity;
+use crate::world::World;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_conveyor_sets_tangential_velocity() {
+    let mut world = World::new(1000.0, 1000.0);
+    let belt = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 500.0, 1000.0, 20.0))));
+
+    // Resting on top of the belt, just outside of it, and falling onto it
+    let resting = Rc::new(RefCell::new(CircleEntity::new(300.0, 490.0)));
+    resting.borrow_mut().velocity.y = 5.0;
+    let below = Rc::new(RefCell::new(CircleEntity::new(500.0, 530.5)));
+    let flying = Rc::new(RefCell::new(CircleEntity::new(700.0, 400.0)));
+    world.add_entity(resting.clone());
+    world.add_entity(below.clone());
+    world.add_entity(flying.clone());
+
+    world.add_effector(Box::new(Conveyor::new(belt, 30.0)));
+    world.update(0.1);
+
+    // On top the belt moves right, on the underside the same belt moves left
+    assert_eq!(resting.borrow().get_velocity().x, 30.0);
+    assert_eq!(resting.borrow().get_velocity().y, 5.0);
+    assert_eq!(below.borrow().get_velocity().x, -30.0);
+    assert_eq!(flying.borrow().get_velocity().x, 0.0);
+}
+
+#[test]
+fn test_conveyor_on_a_dynamic_surface_carries_others_but_not_itself() {
+    let mut world = World::new(1000.0, 1000.0);
+    let board = Rc::new(RefCell::new(RectangleEntity::new(0.0, 500.0, 1000.0, 20.0)));
+    board.borrow_mut().velocity.x = 10.0;
+    let belt = world.add_entity(board.clone());
+    let rider = Rc::new(RefCell::new(CircleEntity::new(300.0, 490.0)));
+    world.add_entity(rider.clone());
+
+    world.add_effector(Box::new(Conveyor::new(belt, 30.0)));
+    world.update(0.1);
+
+    assert_eq!(rider.borrow().get_velocity().x, 40.0);
+    assert_eq!(board.borrow().get_velocity().x, 10.0);
+}
@@ -0,0 +1,104 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::EntityType;
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+/// Electrostatic force `k·q1·q2/r²` between every pair of selected charged entities.
+/// Like charges repel and opposite charges attract.
+#[derive(Clone)]
+pub struct Coulomb {
+    pub selector: Selector,
+    pub k: f32,
+    /// Plummer softening length, keeps opposite charges from collapsing into each other.
+    pub softening: f32,
+}
+
+impl Coulomb {
+    pub fn new(k: f32, softening: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            k,
+            softening,
+        }
+    }
+
+    /// Electrostatic force on each of the given bodies.
+    pub fn forces(&self, positions: &[Vec2], charges: &[f32]) -> Vec<Vec2> {
+        let n = positions.len();
+        let mut forces = vec![Vec2::zeros(); n];
+        let eps_sq = self.softening * self.softening;
+
+        for i in 0..n {
+            if charges[i] == 0.0 {
+                continue;
+            }
+
+            for j in (i + 1)..n {
+                let d = positions[j] - positions[i];
+                let soft = d.dot(&d) + eps_sq;
+                let f = d * (-self.k * charges[i] * charges[j] / (soft * soft.sqrt()));
+
+                forces[i] += f;
+                forces[j] -= f;
+            }
+        }
+
+        forces
+    }
+}
+
+impl Effector for Coulomb {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        let charged: Vec<&EntityRef> = targets.iter().filter(|e| e.borrow().get_charge() != 0.0).collect();
+        let positions: Vec<Vec2> = charged.iter().map(|e| e.borrow().get_center()).collect();
+        let charges: Vec<f32> = charged.iter().map(|e| e.borrow().get_charge()).collect();
+
+        for (entity, force) in charged.iter().zip(self.forces(&positions, &charges)) {
+            let mut entity = entity.borrow_mut();
+
+            if matches!(entity.get_type(), EntityType::Dynamic) {
+                entity.apply_force(force);
+            }
+        }
+    }
+
+    fn potential_energy(&self, targets: &[EntityRef]) -> f32 {
+        let charges: Vec<(Vec2, f32)> = targets.iter()
+            .map(|e| (e.borrow().get_center(), e.borrow().get_charge()))
+            .filter(|(_, q)| *q != 0.0)
+            .collect();
+        let eps_sq = self.softening * self.softening;
+        let mut energy = 0.0;
+
+        for (i, (pi, qi)) in charges.iter().enumerate() {
+            for (pj, qj) in &charges[i + 1..] {
+                let d = *pj - *pi;
+                energy += self.k * qi * qj / (d.dot(&d) + eps_sq).sqrt();
+            }
+        }
+
+        energy
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
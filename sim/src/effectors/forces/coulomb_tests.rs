@@ -0,0 +1,46 @@
+use super::coulomb::Coulomb;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::Entity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_coulomb_law() {
+    let coulomb = Coulomb::new(100.0, 0.0);
+    let positions = [Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)];
+
+    // k·q1·q2/r² = 100·2·3/100, pushing like charges apart
+    let like = coulomb.forces(&positions, &[2.0, 3.0]);
+    assert!((like[0].x + 6.0).abs() < 1e-4);
+    assert!((like[1].x - 6.0).abs() < 1e-4);
+
+    let opposite = coulomb.forces(&positions, &[2.0, -3.0]);
+    assert!((opposite[0].x - 6.0).abs() < 1e-4);
+
+    let neutral = coulomb.forces(&positions, &[0.0, 3.0]);
+    assert_eq!(neutral[1].length(), 0.0);
+}
+
+#[test]
+fn test_like_charges_repel_in_world() {
+    let mut world = World::new(1000.0, 1000.0);
+    let charged = |x: f32, q: f32| {
+        let mut e = CircleEntity::new(x, 500.0);
+        e.charge = q;
+        Rc::new(RefCell::new(e))
+    };
+
+    let (a, b, neutral) = (charged(400.0, 1.0), charged(600.0, 1.0), charged(500.0, 0.0));
+    world.add_entity(a.clone());
+    world.add_entity(b.clone());
+    world.add_entity(neutral.clone());
+    world.add_effector(Box::new(Coulomb::new(1e5, 1.0)));
+
+    world.step(0.1);
+
+    assert!(a.borrow().get_velocity().x < 0.0);
+    assert!(b.borrow().get_velocity().x > 0.0);
+    assert_eq!(neutral.borrow().get_velocity().length(), 0.0);
+}
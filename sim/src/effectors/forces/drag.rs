@@ -0,0 +1,80 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::entity::Entity;
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+/// Drag law shared by `Drag` and `WindField`, scaled by the collider's cross-section.
+#[derive(Copy, Clone)]
+pub struct DragCoefficients {
+    /// Force per unit of cross-section and speed.
+    pub linear: f32,
+    /// Force per unit of cross-section and squared speed.
+    pub quadratic: f32,
+}
+
+impl DragCoefficients {
+    /// Drag force on `entity` when the air around it moves at `air_velocity`.
+    pub fn force(&self, entity: &dyn Entity, air_velocity: Vec2) -> Vec2 {
+        let relative = entity.get_velocity() - air_velocity;
+        let speed = relative.length();
+        if speed == 0.0 {
+            return Vec2::zeros();
+        }
+
+        let area = entity.get_collider().cross_section(&(relative / speed));
+        relative * (-area * (self.linear + self.quadratic * speed))
+    }
+}
+
+/// Aerodynamic drag through still air, plus a plain angular damping.
+#[derive(Clone)]
+pub struct Drag {
+    pub selector: Selector,
+    pub coefficients: DragCoefficients,
+    /// Torque per unit of angular velocity.
+    pub angular: f32,
+}
+
+impl Drag {
+    pub fn new(linear: f32, quadratic: f32, angular: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            coefficients: DragCoefficients { linear, quadratic },
+            angular,
+        }
+    }
+}
+
+impl Effector for Drag {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        let (coefficients, angular) = (self.coefficients, self.angular);
+
+        for_each_dynamic(targets, |entity| {
+            let f = coefficients.force(entity, Vec2::zeros());
+            entity.apply_force(f);
+            entity.apply_torque(-entity.get_angular_velocity() * angular);
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
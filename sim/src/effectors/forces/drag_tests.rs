@@ -0,0 +1,108 @@
+use super::drag::{Drag, DragCoefficients};
+use super::wind_field::{WindField, WindSource};
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const COEFFICIENTS: DragCoefficients = DragCoefficients { linear: 0.01, quadratic: 0.001 };
+
+#[test]
+fn test_drag_scales_with_cross_section() {
+    let mut circle = CircleEntity::new(0.0, 0.0);
+    circle.velocity = Vec2::new(10.0, 0.0);
+
+    // Radius 10 gives a cross-section of 20: 20·(0.01·10 + 0.001·10·10)
+    let f = COEFFICIENTS.force(&circle, Vec2::zeros());
+    assert!((f.x + 4.0).abs() < 1e-4);
+    assert_eq!(f.y, 0.0);
+
+    let mut wide = RectangleEntity::new(0.0, 0.0, 40.0, 10.0);
+    wide.velocity = Vec2::new(0.0, 10.0);
+    let mut narrow = RectangleEntity::new(0.0, 0.0, 40.0, 10.0);
+    narrow.velocity = Vec2::new(10.0, 0.0);
+
+    assert!(COEFFICIENTS.force(&wide, Vec2::zeros()).length() > COEFFICIENTS.force(&narrow, Vec2::zeros()).length());
+}
+
+/// Velocity of a ball after ten seconds in a 20 px/s wind, blowing over a still air `Drag` if `over_drag` is set.
+fn drift(over_drag: bool, coefficients: DragCoefficients) -> Vec2 {
+    let mut world = World::new(10000.0, 10000.0);
+    let source = WindSource::Uniform(Vec2::new(20.0, 0.0));
+    let wind = if over_drag {
+        let drag = Drag::new(coefficients.linear, coefficients.quadratic, 0.0);
+        let wind = WindField::with_drag(source, &drag);
+        world.add_effector(Box::new(drag));
+        wind
+    } else {
+        WindField::new(source, coefficients)
+    };
+    world.add_effector(Box::new(wind));
+    let handle = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+
+    for _ in 0..600 {
+        world.step(1.0 / 60.0);
+    }
+
+    world.get_entity(handle).unwrap().borrow().get_velocity()
+}
+
+#[test]
+fn test_wind_carries_bodies_to_air_speed() {
+    let coefficients = DragCoefficients { linear: 0.1, quadratic: 0.001 };
+    assert!((drift(true, coefficients).x - 20.0).abs() < 0.1);
+    assert!((drift(false, coefficients).x - 20.0).abs() < 0.1);
+}
+
+#[test]
+fn test_wind_over_drag_adds_nothing_in_still_air() {
+    let drag = Drag::new(0.1, 0.001, 0.0).with_selector(Selector::Tag("sail".to_string()));
+    let wind = WindField::with_drag(WindSource::Uniform(Vec2::zeros()), &drag);
+    assert!(matches!(&wind.selector, Selector::Tag(tag) if tag == "sail"));
+
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_effector(Box::new(wind));
+    let mut ball = CircleEntity::new(100.0, 100.0);
+    ball.velocity = Vec2::new(10.0, 0.0);
+    ball.tags = vec!["sail".to_string()];
+    let handle = world.add_entity(Rc::new(RefCell::new(ball)));
+
+    world.step(1.0 / 60.0);
+
+    // The still air part is left to the drag effector, which this world does not have
+    assert_eq!(world.get_entity(handle).unwrap().borrow().get_velocity(), Vec2::new(10.0, 0.0));
+}
+
+#[test]
+fn test_wind_grid_parsing_and_sampling() {
+    let grid = WindSource::parse_grid("# test grid\n2 2 10 0 0\n0,0 10,0\n0,10 10,10\n").unwrap();
+
+    let v = grid.sample(Vec2::new(5.0, 5.0), 0.0);
+    assert!((v.x - 5.0).abs() < 1e-5);
+    assert!((v.y - 5.0).abs() < 1e-5);
+
+    // Positions outside the grid clamp to the nearest edge
+    let v = grid.sample(Vec2::new(100.0, -50.0), 0.0);
+    assert!((v.x - 10.0).abs() < 1e-5);
+    assert_eq!(v.y, 0.0);
+
+    assert!(WindSource::parse_grid("2 2 10\n0,0 1,1\n").is_err());
+}
+
+#[test]
+fn test_noise_wind_is_bounded_and_smooth() {
+    let noise = WindSource::Noise { base: Vec2::zeros(), amplitude: 5.0, scale: 0.01, speed: 1.0 };
+
+    for i in 0..100 {
+        let p = Vec2::new(i as f32 * 13.0, i as f32 * 7.0);
+        let v = noise.sample(p, i as f32 * 0.1);
+        assert!(v.x.abs() <= 5.0 && v.y.abs() <= 5.0);
+
+        let nearby = noise.sample(p + Vec2::new(0.1, 0.0), i as f32 * 0.1);
+        assert!((v - nearby).length() < 0.5);
+    }
+}
@@ -0,0 +1,160 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::entity::EntityType;
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+use macroquad::color::Color;
+use macroquad::shapes::draw_circle_lines;
+
+const DEFAULT_LIFETIME: f32 = 0.3;
+
+/// How the impulse fades from full strength at the centre to nothing at the blast radius.
+#[derive(Clone)]
+pub enum BlastFalloff {
+    Constant,
+    Linear,
+    Quadratic,
+}
+
+impl BlastFalloff {
+    fn factor(&self, r: f32, radius: f32) -> f32 {
+        let t = (1.0 - r / radius).clamp(0.0, 1.0);
+
+        match self {
+            BlastFalloff::Constant => 1.0,
+            BlastFalloff::Linear => t,
+            BlastFalloff::Quadratic => t * t,
+        }
+    }
+}
+
+/// One-shot radial impulse on the bodies overlapping the blast radius. Fires on its first update, then lingers as an expanding
+/// shock ring until its lifetime runs out and the world removes it.
+#[derive(Clone)]
+pub struct Explosion {
+    pub selector: Selector,
+    pub center: Vec2,
+    /// Impulse received by a body sitting at the centre.
+    pub strength: f32,
+    pub radius: f32,
+    pub falloff: BlastFalloff,
+    /// Shield bodies behind static entities from the blast.
+    pub occlusion: bool,
+    pub lifetime: f32,
+    age: f32,
+    fired: bool,
+}
+
+impl Explosion {
+    pub fn new(center: Vec2, strength: f32, radius: f32) -> Self {
+        Self {
+            selector: Selector::circle(center, radius),
+            center,
+            strength,
+            radius,
+            falloff: BlastFalloff::Linear,
+            occlusion: true,
+            lifetime: DEFAULT_LIFETIME,
+            age: 0.0,
+            fired: false,
+        }
+    }
+
+    pub fn with_falloff(mut self, falloff: BlastFalloff) -> Self {
+        self.falloff = falloff;
+        self
+    }
+
+    pub fn with_occlusion(mut self, occlusion: bool) -> Self {
+        self.occlusion = occlusion;
+        self
+    }
+
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// Impulse a body centred at `pos` receives, ignoring occlusion.
+    pub fn impulse(&self, pos: Vec2) -> Vec2 {
+        let d = pos - self.center;
+        let r = d.length();
+        if r == 0.0 || r > self.radius {
+            return Vec2::zeros();
+        }
+
+        d / r * (self.strength * self.falloff.factor(r, self.radius))
+    }
+}
+
+/// Whether the segment from `from` to `to` passes through any of the `blockers`.
+fn occluded(blockers: &[EntityRef], from: Vec2, to: Vec2) -> bool {
+    let d = to - from;
+    let dist = d.length();
+    if dist == 0.0 {
+        return false;
+    }
+
+    blockers.iter().any(|blocker| {
+        let blocker = blocker.borrow();
+        blocker.get_collider().ray_cast(&blocker.get_position(), from, d / dist, dist).is_some()
+    })
+}
+
+impl Effector for Explosion {
+    fn update(&mut self, targets: &[EntityRef], entities: &EntityMap, delta: f32) {
+        self.age += delta;
+        if self.fired {
+            return;
+        }
+        self.fired = true;
+
+        let blockers: Vec<EntityRef> = if self.occlusion {
+            entities.values()
+                .filter(|e| matches!(e.borrow().get_type(), EntityType::Static))
+                .cloned()
+                .collect()
+        } else {
+            vec![]
+        };
+
+        for_each_dynamic(targets, |entity| {
+            let center = entity.get_center();
+            if !occluded(&blockers, self.center, center) {
+                entity.apply_impulse(self.impulse(center));
+            }
+        });
+    }
+
+    fn is_expired(&self) -> bool {
+        self.fired && self.age >= self.lifetime
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn render(&self) {
+        let progress = (self.age / self.lifetime).clamp(0.0, 1.0);
+        let color = Color::new(1.0, 0.6, 0.1, 1.0 - progress);
+
+        draw_circle_lines(self.center.x, self.center.y, self.radius * progress, 3.0, color);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
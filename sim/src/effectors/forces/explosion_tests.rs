@@ -0,0 +1,55 @@
+use super::explosion::{BlastFalloff, Explosion};
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_impulse_falloff() {
+    let explosion = Explosion::new(Vec2::zeros(), 100.0, 200.0);
+    assert!((explosion.impulse(Vec2::new(100.0, 0.0)).x - 50.0).abs() < 1e-4);
+    assert_eq!(explosion.impulse(Vec2::new(0.0, 300.0)).length(), 0.0);
+
+    let explosion = explosion.with_falloff(BlastFalloff::Quadratic);
+    assert!((explosion.impulse(Vec2::new(0.0, -100.0)).y + 25.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_static_bodies_occlude() {
+    let mut world = World::new(1000.0, 1000.0);
+    let exposed = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(400.0, 500.0))));
+    let hidden = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(600.0, 500.0))));
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(540.0, 400.0, 20.0, 200.0))));
+
+    world.add_effector(Box::new(Explosion::new(Vec2::new(500.0, 500.0), 100.0, 200.0)));
+    world.update(0.01);
+
+    let velocity = |handle| world.get_entity(handle).unwrap().borrow().get_velocity();
+    assert!(velocity(exposed).x < 0.0);
+    assert_eq!(velocity(hidden).x, 0.0);
+}
+
+#[test]
+fn test_expires_after_lifetime() {
+    let mut world = World::new(1000.0, 1000.0);
+    let circle = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(600.0, 500.0))));
+
+    let explosion = world.add_effector(Box::new(
+        Explosion::new(Vec2::new(500.0, 500.0), 100.0, 200.0)
+            .with_occlusion(false)
+            .with_lifetime(0.05),
+    ));
+    world.update(0.02);
+    let after_blast = world.get_entity(circle).unwrap().borrow().get_velocity();
+    assert!(after_blast.x > 0.0);
+
+    world.update(0.02);
+    assert!(world.get_effector_mut(explosion).is_some());
+    world.update(0.02);
+    assert!(world.get_effector_mut(explosion).is_none());
+
+    // The impulse is only ever applied once
+    assert_eq!(world.get_entity(circle).unwrap().borrow().get_velocity().x, after_blast.x);
+}
@@ -0,0 +1,146 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::entity::Entity;
+use crate::world::{EntityMap, EntityRef};
+use common::math::polygon::{area_and_centroid, clip_convex, clip_half_plane};
+use common::math::vectors::Vec2;
+use macroquad::color::Color;
+use macroquad::math::vec2;
+use macroquad::shapes::draw_triangle;
+
+const FLUID_COLOR: Color = Color::new(0.2, 0.4, 0.9, 0.35);
+
+#[derive(Clone)]
+pub enum FluidRegion {
+    Rectangle { min: Vec2, max: Vec2 },
+    /// Convex polygon, in either winding.
+    Polygon(Vec<Vec2>),
+}
+
+impl FluidRegion {
+    fn to_polygon(&self) -> Vec<Vec2> {
+        match self {
+            FluidRegion::Rectangle { min, max } => vec![
+                *min,
+                Vec2::new(max.x, min.y),
+                *max,
+                Vec2::new(min.x, max.y),
+            ],
+            FluidRegion::Polygon(points) => points.clone(),
+        }
+    }
+}
+
+/// A body of still fluid. Entities are pushed up by the weight of the fluid they displace,
+/// so they float when they are less dense than the fluid, and are slowed down while submerged.
+#[derive(Clone)]
+pub struct FluidVolume {
+    pub selector: Selector,
+    pub region: FluidRegion,
+    /// Mass per unit of area, compared against `Entity::get_density`.
+    pub density: f32,
+    /// Drag force per unit of submerged area and speed.
+    pub linear_drag: f32,
+    /// Drag torque per unit of submerged fraction and angular velocity.
+    pub angular_drag: f32,
+    /// Height of the free surface, the fluid only fills the region below it.
+    pub surface_level: f32,
+    /// Gravity the fluid's weight is computed with, usually the world's uniform gravity.
+    pub gravity: Vec2,
+}
+
+impl FluidVolume {
+    pub fn new(region: FluidRegion, density: f32, linear_drag: f32, gravity: Vec2) -> Self {
+        let surface_level = region.to_polygon().iter().map(|p| p.y).fold(f32::INFINITY, f32::min);
+
+        Self {
+            selector: Selector::AllDynamic,
+            region,
+            density,
+            linear_drag,
+            angular_drag: linear_drag,
+            surface_level,
+            gravity,
+        }
+    }
+
+    pub fn with_surface_level(mut self, surface_level: f32) -> Self {
+        self.surface_level = surface_level;
+        self
+    }
+
+    /// The fluid's outline: the region cut at the surface level.
+    pub fn fluid_polygon(&self) -> Vec<Vec2> {
+        clip_half_plane(&self.region.to_polygon(), Vec2::new(0.0, self.surface_level), Vec2::new(0.0, 1.0))
+    }
+}
+
+/// Area of the entity inside the `fluid` polygon and the centre of buoyancy it acts through.
+pub fn submerged(fluid: &[Vec2], entity: &dyn Entity) -> (f32, Vec2) {
+    let collider = entity.get_collider();
+    let shape = collider.to_polygon(&entity.get_position());
+
+    let (shape_area, _) = area_and_centroid(&shape);
+    let (area, centroid) = area_and_centroid(&clip_convex(&shape, fluid));
+    if area == 0.0 || shape_area == 0.0 {
+        return (0.0, Vec2::zeros());
+    }
+
+    // Correct for the polygon approximating round colliders
+    (area.abs() * collider.area() / shape_area.abs(), centroid)
+}
+
+impl Effector for FluidVolume {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        let fluid = self.fluid_polygon();
+        if fluid.len() < 3 {
+            return;
+        }
+
+        let (density, gravity) = (self.density, self.gravity);
+        let (linear_drag, angular_drag) = (self.linear_drag, self.angular_drag);
+
+        for_each_dynamic(targets, |entity| {
+            let (area, center) = submerged(&fluid, entity);
+            if area == 0.0 {
+                return;
+            }
+
+            let fraction = area / entity.get_collider().area();
+
+            entity.apply_force_at(-gravity * (density * area), center);
+            entity.apply_force(entity.get_velocity() * (-linear_drag * area));
+            entity.apply_torque(-entity.get_angular_velocity() * angular_drag * fraction);
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn render(&self) {
+        let fluid = self.fluid_polygon();
+
+        for i in 1..fluid.len().saturating_sub(1) {
+            let (a, b, c) = (fluid[0], fluid[i], fluid[i + 1]);
+            draw_triangle(vec2(a.x, a.y), vec2(b.x, b.y), vec2(c.x, c.y), FLUID_COLOR);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,91 @@
+use super::fluid_volume::{submerged, FluidRegion, FluidVolume};
+use super::uniform_gravity::UniformGravity;
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+const GRAVITY: Vec2 = Vec2 { x: 0.0, y: 100.0 };
+
+fn pool(density: f32) -> FluidVolume {
+    FluidVolume::new(
+        FluidRegion::Rectangle { min: Vec2::new(0.0, 500.0), max: Vec2::new(1000.0, 1000.0) },
+        density,
+        0.01,
+        GRAVITY,
+    )
+}
+
+#[test]
+fn test_submerged_area() {
+    let pool = pool(1.0);
+    let fluid = pool.fluid_polygon();
+
+    let half = RectangleEntity::new(100.0, 490.0, 20.0, 20.0);
+    let (area, center) = submerged(&fluid, &half);
+    assert!((area - 200.0).abs() < 1e-3);
+    assert!((center.y - 505.0).abs() < 1e-3);
+
+    let circle = CircleEntity::new(100.0, 700.0);
+    assert!((submerged(&fluid, &circle).0 - PI * 100.0).abs() < 1e-2);
+
+    let dry = CircleEntity::new(100.0, 100.0);
+    assert_eq!(submerged(&fluid, &dry).0, 0.0);
+}
+
+#[test]
+fn test_surface_level_cuts_region() {
+    let pool = pool(1.0).with_surface_level(600.0);
+    let half = RectangleEntity::new(100.0, 590.0, 20.0, 20.0);
+
+    assert!((submerged(&pool.fluid_polygon(), &half).0 - 200.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_buoyancy_depends_on_density() {
+    // A 20x20 rectangle of mass 2 has a density of 0.005, between the two fluids
+    let mut dense = pool(0.01);
+    let mut thin = pool(0.001);
+
+    let mut world = World::new(1000.0, 1000.0);
+    let floating = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(100.0, 700.0, 20.0, 20.0))));
+    let sinking = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(300.0, 700.0, 20.0, 20.0))));
+
+    dense.update(&[Rc::clone(world.get_entity(floating).unwrap())], &world.entities, 0.1);
+    thin.update(&[Rc::clone(world.get_entity(sinking).unwrap())], &world.entities, 0.1);
+
+    // Buoyancy alone, against a weight m·g of 200: the dense fluid lifts with 400, the thin one with 40
+    let floating = world.get_entity(floating).unwrap().borrow();
+    let sinking = world.get_entity(sinking).unwrap().borrow();
+    assert!(floating.get_density() < 0.01);
+    assert!((floating.get_force().y + 400.0).abs() < 1e-2);
+    assert!((sinking.get_force().y + 40.0).abs() < 1e-2);
+}
+
+#[test]
+fn test_lighter_body_rises_and_denser_body_sinks() {
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_effector(Box::new(UniformGravity::new(GRAVITY)));
+    world.add_effector(Box::new(pool(0.01).with_selector(Selector::Tag("dense".to_string()))));
+    world.add_effector(Box::new(pool(0.001).with_selector(Selector::Tag("thin".to_string()))));
+
+    let mut bodies = vec![];
+    for (x, tag) in [(100.0, "dense"), (300.0, "thin")] {
+        let mut body = RectangleEntity::new(x, 700.0, 20.0, 20.0);
+        body.tags.push(tag.to_string());
+        bodies.push(world.add_entity(Rc::new(RefCell::new(body))));
+    }
+
+    for _ in 0..30 {
+        world.step(1.0 / 60.0);
+    }
+
+    let y = |handle| world.get_entity(handle).unwrap().borrow().get_position().y;
+    assert!(y(bodies[0]) < 700.0, "a body lighter than its fluid should rise");
+    assert!(y(bodies[1]) > 700.0, "a body denser than its fluid should sink");
+}
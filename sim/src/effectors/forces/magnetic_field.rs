@@ -0,0 +1,57 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+/// Magnetic field perpendicular to the screen, bending charged bodies into circles
+/// through the Lorentz force `q·v×B`. Positive `b` points out of the screen.
+#[derive(Clone)]
+pub struct UniformMagneticField {
+    pub selector: Selector,
+    pub b: f32,
+}
+
+impl UniformMagneticField {
+    pub fn new(b: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            b,
+        }
+    }
+
+    /// Lorentz force on a charge `q` moving with `velocity`.
+    pub fn force(&self, q: f32, velocity: Vec2) -> Vec2 {
+        // v×B with B = (0, 0, b)
+        Vec2::new(velocity.y, -velocity.x) * (q * self.b)
+    }
+}
+
+impl Effector for UniformMagneticField {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        for_each_dynamic(targets, |entity| {
+            let f = self.force(entity.get_charge(), entity.get_velocity());
+            entity.apply_force(f);
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,49 @@
+use super::magnetic_field::UniformMagneticField;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::Entity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::f32::consts::PI;
+use std::rc::Rc;
+
+#[test]
+fn test_lorentz_force_is_perpendicular() {
+    let field = UniformMagneticField::new(2.0);
+    let v = Vec2::new(3.0, 4.0);
+    let f = field.force(1.5, v);
+
+    assert!(f.dot(&v).abs() < 1e-5);
+    assert!((f.length() - 1.5 * 2.0 * 5.0).abs() < 1e-4);
+    assert_eq!(field.force(0.0, v).length(), 0.0);
+}
+
+#[test]
+fn test_cyclotron_orbit() {
+    let (q, b, speed) = (1.0, 2.0, 50.0);
+
+    let mut particle = CircleEntity::new(500.0, 500.0);
+    particle.charge = q;
+    particle.velocity = Vec2::new(speed, 0.0);
+    let mass = particle.mass;
+    let particle = Rc::new(RefCell::new(particle));
+
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_entity(particle.clone());
+    world.add_effector(Box::new(UniformMagneticField::new(b)));
+
+    // One full period 2πm/(qB) brings the particle back where it started
+    let steps = 2000;
+    let dt = 2.0 * PI * mass / (q * b) / steps as f32;
+    let mut furthest: f32 = 0.0;
+    for _ in 0..steps {
+        world.step(dt);
+        furthest = furthest.max(particle.borrow().get_position().dist(&Vec2::new(500.0, 500.0)));
+    }
+
+    // The orbit's diameter is 2mv/(qB)
+    let diameter = 2.0 * mass * speed / (q * b);
+    assert!((furthest - diameter).abs() < 0.02 * diameter);
+    assert!(particle.borrow().get_position().dist(&Vec2::new(500.0, 500.0)) < 0.05 * diameter);
+    assert!((particle.borrow().get_velocity().length() - speed).abs() < 0.02 * speed);
+}
@@ -1 +1,39 @@
-pub mod mouse_gravity;
\ No newline at end of file
+mod barnes_hut;
+pub mod conveyor;
+pub mod coulomb;
+pub mod drag;
+pub mod explosion;
+pub mod fluid_volume;
+pub mod magnetic_field;
+pub mod mouse_gravity;
+pub mod mutual_gravity;
+pub mod point_attractor;
+pub mod push_zone;
+pub mod thruster;
+pub mod uniform_gravity;
+pub mod vortex;
+pub mod wind_field;
+#[cfg(test)]
+mod conveyor_tests;
+#[cfg(test)]
+mod coulomb_tests;
+#[cfg(test)]
+mod drag_tests;
+#[cfg(test)]
+mod explosion_tests;
+#[cfg(test)]
+mod fluid_volume_tests;
+#[cfg(test)]
+mod magnetic_field_tests;
+#[cfg(test)]
+mod mutual_gravity_tests;
+#[cfg(test)]
+mod point_attractor_tests;
+#[cfg(test)]
+mod push_zone_tests;
+#[cfg(test)]
+mod thruster_tests;
+#[cfg(test)]
+mod uniform_gravity_tests;
+#[cfg(test)]
+mod vortex_tests;
@@ -1,55 +1,59 @@
 use std::any::Any;
-use crate::effectors::Effector;
-use crate::world::WeakEntityRef;
-use macroquad::input::mouse_position;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::world::{EntityMap, EntityRef};
 use common::math::vectors::Vec2;
 
+#[derive(Clone)]
 pub struct MouseGravity {
-    pub entities: Vec<WeakEntityRef>,
-    force: f32
+    pub selector: Selector,
+    pub force: f32,
+    pointer: Vec2,
 }
 
 impl MouseGravity {
     pub fn new(force: f32) -> Self {
         Self {
-            entities: vec![],
+            selector: Selector::AllDynamic,
             force,
+            pointer: Vec2::zeros(),
         }
     }
 }
 
 impl Effector for MouseGravity {
-    fn update(&mut self, _delta: f32) {
-        let (x, y) = mouse_position();
-        let mouse_positon = Vec2::new(x, y);
-
-        self.entities.retain(|entity| {
-            if let Some(entity) = entity.upgrade() {
-                let mut entity = entity.borrow_mut();
-
-                let f = mouse_positon - entity.get_position();
-                let r = f.length();
-                let f = if r > 0.0 {f.unit() * r.powi(2)} else {Vec2::zeros()} ;
-                let f = f * self.force;
-
-                entity.apply_force(f);
-
-                true
-            } else {
-                false
-            }
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        for_each_dynamic(targets, |entity| {
+            let f = self.pointer - entity.get_position();
+            let r = f.length();
+            let f = if r > 0.0 {f.unit() * r.powi(2)} else {Vec2::zeros()} ;
+            let f = f * self.force;
+
+            entity.apply_force(f);
         });
     }
 
-    fn get_entities(&self) -> &Vec<WeakEntityRef> {
-        &self.entities
+    fn set_pointer(&mut self, pointer: Vec2) {
+        self.pointer = pointer;
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
     }
 
-    fn get_entities_mut(&mut self) -> &mut Vec<WeakEntityRef> {
-        &mut self.entities
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
     }
 
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
 }
@@ -0,0 +1,114 @@
+use std::any::Any;
+use crate::effectors::forces::barnes_hut::QuadTree;
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::EntityType;
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+const DEFAULT_THETA: f32 = 0.5;
+const DEFAULT_THRESHOLD: usize = 64;
+
+/// Newtonian attraction `G·m1·m2/r²` between every pair of selected entities.
+/// Switches from the exact pairwise sum to a Barnes–Hut approximation above `threshold` entities.
+#[derive(Clone)]
+pub struct MutualGravity {
+    pub selector: Selector,
+    pub g: f32,
+    /// Plummer softening length, keeps close encounters from blowing up.
+    pub softening: f32,
+    /// Opening angle of the Barnes–Hut approximation, zero makes it exact.
+    pub theta: f32,
+    pub threshold: usize,
+}
+
+impl MutualGravity {
+    pub fn new(g: f32, softening: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            g,
+            softening,
+            theta: DEFAULT_THETA,
+            threshold: DEFAULT_THRESHOLD,
+        }
+    }
+
+    /// Gravitational force on each of the given bodies.
+    pub fn forces(&self, positions: &[Vec2], masses: &[f32]) -> Vec<Vec2> {
+        let n = positions.len();
+
+        if n > self.threshold {
+            let tree = QuadTree::new(positions.to_vec(), masses.to_vec());
+            return (0..n)
+                .map(|i| tree.acceleration(i, self.theta, self.softening) * (self.g * masses[i]))
+                .collect();
+        }
+
+        let mut forces = vec![Vec2::zeros(); n];
+        let eps_sq = self.softening * self.softening;
+
+        for i in 0..n {
+            for j in (i + 1)..n {
+                let d = positions[j] - positions[i];
+                let soft = d.dot(&d) + eps_sq;
+                let f = d * (self.g * masses[i] * masses[j] / (soft * soft.sqrt()));
+
+                forces[i] += f;
+                forces[j] -= f;
+            }
+        }
+
+        forces
+    }
+}
+
+impl Effector for MutualGravity {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        let positions: Vec<Vec2> = targets.iter().map(|e| e.borrow().get_center()).collect();
+        let masses: Vec<f32> = targets.iter().map(|e| e.borrow().get_mass()).collect();
+
+        for (entity, force) in targets.iter().zip(self.forces(&positions, &masses)) {
+            let mut entity = entity.borrow_mut();
+
+            if matches!(entity.get_type(), EntityType::Dynamic) {
+                entity.apply_force(force);
+            }
+        }
+    }
+
+    /// Summed over every pair directly, also when the forces use the tree approximation.
+    fn potential_energy(&self, targets: &[EntityRef]) -> f32 {
+        let bodies: Vec<(Vec2, f32)> = targets.iter().map(|e| (e.borrow().get_center(), e.borrow().get_mass())).collect();
+        let eps_sq = self.softening * self.softening;
+        let mut energy = 0.0;
+
+        for (i, (pi, mi)) in bodies.iter().enumerate() {
+            for (pj, mj) in &bodies[i + 1..] {
+                let d = *pj - *pi;
+                energy -= self.g * mi * mj / (d.dot(&d) + eps_sq).sqrt();
+            }
+        }
+
+        energy
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,85 @@
+use super::mutual_gravity::MutualGravity;
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::circle_entity::CircleEntity;
+use crate::world::{EntityRef, World};
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn scattered_bodies(n: usize) -> (Vec<Vec2>, Vec<f32>) {
+    // Deterministic spiral so the test does not need a random source
+    let positions = (0..n)
+        .map(|i| {
+            let t = i as f32 * 0.7;
+            Vec2::new(500.0 + t.cos() * (10.0 + i as f32 * 3.0), 500.0 + t.sin() * (10.0 + i as f32 * 3.0))
+        })
+        .collect();
+    let masses = (0..n).map(|i| 1.0 + (i % 5) as f32).collect();
+    (positions, masses)
+}
+
+#[test]
+fn test_pairwise_forces_are_symmetric() {
+    let gravity = MutualGravity::new(100.0, 0.0);
+    let forces = gravity.forces(&[Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0)], &[2.0, 3.0]);
+
+    // G·m1·m2/r² = 100·2·3/100
+    assert!((forces[0].x - 6.0).abs() < 1e-4);
+    assert!((forces[1].x + 6.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_barnes_hut_with_zero_theta_is_exact() {
+    let (positions, masses) = scattered_bodies(100);
+
+    let mut gravity = MutualGravity::new(1.0, 1.0);
+    gravity.threshold = usize::MAX;
+    let direct = gravity.forces(&positions, &masses);
+
+    gravity.threshold = 0;
+    gravity.theta = 0.0;
+    let tree = gravity.forces(&positions, &masses);
+
+    for (a, b) in direct.iter().zip(tree.iter()) {
+        assert!((*a - *b).length() <= 1e-4 * a.length().max(1e-3));
+    }
+}
+
+#[test]
+fn test_barnes_hut_approximates_direct_sum() {
+    let (positions, masses) = scattered_bodies(200);
+
+    let mut gravity = MutualGravity::new(1.0, 1.0);
+    gravity.threshold = usize::MAX;
+    let direct = gravity.forces(&positions, &masses);
+
+    gravity.threshold = 0;
+    let tree = gravity.forces(&positions, &masses);
+
+    let error: f32 = direct.iter().zip(tree.iter()).map(|(a, b)| (*a - *b).length()).sum();
+    let total: f32 = direct.iter().map(|a| a.length()).sum();
+    assert!(error / total < 0.05);
+}
+
+#[test]
+fn test_mutual_gravity_pulls_selected_entities_together() {
+    let mut world = World::new(1000.0, 1000.0);
+    let a: EntityRef = Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0)));
+    let b: EntityRef = Rc::new(RefCell::new(CircleEntity::new(200.0, 100.0)));
+    let mut excluded = CircleEntity::new(150.0, 150.0);
+    excluded.groups = 1 << 3;
+    let excluded: EntityRef = Rc::new(RefCell::new(excluded));
+    world.add_entity(Rc::clone(&a));
+    world.add_entity(Rc::clone(&b));
+    world.add_entity(Rc::clone(&excluded));
+
+    world.add_effector(Box::new(MutualGravity::new(1e5, 1.0).with_selector(Selector::Groups(1))));
+
+    world.step(0.1);
+
+    assert!(a.borrow().get_velocity().x > 0.0);
+    assert!(b.borrow().get_velocity().x < 0.0);
+    assert_eq!(a.borrow().get_velocity().y, 0.0);
+    assert_eq!(excluded.borrow().get_velocity().length(), 0.0);
+}
@@ -0,0 +1,150 @@
+use std::any::Any;
+use std::rc::Rc;
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::EntityType;
+use crate::world::{EntityHandle, EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+#[derive(Clone)]
+pub enum AttractorSource {
+    Fixed(Vec2),
+    /// Follows the pointer position handed to the world.
+    Mouse,
+    /// Follows the centre of an entity, which is itself left untouched.
+    Entity(EntityHandle),
+}
+
+/// How the pull scales with the distance `r` to the source.
+#[derive(Clone)]
+pub enum Falloff {
+    InverseSquare,
+    LinearSpring,
+    Constant,
+    Custom(Rc<dyn Fn(f32) -> f32>),
+}
+
+impl Falloff {
+    fn factor(&self, r: f32) -> f32 {
+        match self {
+            Falloff::InverseSquare => 1.0 / (r * r),
+            Falloff::LinearSpring => r,
+            Falloff::Constant => 1.0,
+            Falloff::Custom(f) => f(r),
+        }
+    }
+}
+
+/// Pulls the selected entities towards a point with a configurable falloff.
+/// The strength is an acceleration, so entities of different mass respond alike.
+#[derive(Clone)]
+pub struct PointAttractor {
+    pub selector: Selector,
+    pub source: AttractorSource,
+    pub falloff: Falloff,
+    pub strength: f32,
+    /// Distances below this are clamped to it, keeping inverse laws finite.
+    pub min_radius: f32,
+    /// Entities further away than this are not affected.
+    pub max_radius: f32,
+    pub repel: bool,
+    pointer: Vec2,
+}
+
+impl PointAttractor {
+    pub fn new(source: AttractorSource, falloff: Falloff, strength: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            source,
+            falloff,
+            strength,
+            min_radius: 1.0,
+            max_radius: f32::INFINITY,
+            repel: false,
+            pointer: Vec2::zeros(),
+        }
+    }
+
+    pub fn with_radius(mut self, min_radius: f32, max_radius: f32) -> Self {
+        self.min_radius = min_radius;
+        self.max_radius = max_radius;
+        self
+    }
+
+    pub fn with_repel(mut self, repel: bool) -> Self {
+        self.repel = repel;
+        self
+    }
+
+    fn source_position(&self, entities: &EntityMap) -> Option<Vec2> {
+        match &self.source {
+            AttractorSource::Fixed(p) => Some(*p),
+            AttractorSource::Mouse => Some(self.pointer),
+            AttractorSource::Entity(handle) => entities.get(handle).map(|e| e.borrow().get_center()),
+        }
+    }
+
+    /// Acceleration the attractor imparts on a body at `pos` when the source is at `source`.
+    pub fn acceleration(&self, source: Vec2, pos: Vec2) -> Vec2 {
+        let d = source - pos;
+        let r = d.length();
+        if r == 0.0 || r > self.max_radius {
+            return Vec2::zeros();
+        }
+
+        let magnitude = self.strength * self.falloff.factor(r.max(self.min_radius));
+        let a = d / r * magnitude;
+
+        if self.repel { -a } else { a }
+    }
+}
+
+impl Effector for PointAttractor {
+    fn update(&mut self, targets: &[EntityRef], entities: &EntityMap, _delta: f32) {
+        let Some(source) = self.source_position(entities) else {
+            return;
+        };
+        let source_entity = match &self.source {
+            AttractorSource::Entity(handle) => entities.get(handle).cloned(),
+            _ => None,
+        };
+
+        for entity in targets {
+            if source_entity.as_ref().is_some_and(|s| Rc::ptr_eq(s, entity)) {
+                continue;
+            }
+
+            let mut entity = entity.borrow_mut();
+            if !matches!(entity.get_type(), EntityType::Dynamic) {
+                continue;
+            }
+
+            let f = self.acceleration(source, entity.get_center()) * entity.get_mass();
+            entity.apply_force(f);
+        }
+    }
+
+    fn set_pointer(&mut self, pointer: Vec2) {
+        self.pointer = pointer;
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
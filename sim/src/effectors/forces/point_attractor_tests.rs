@@ -0,0 +1,41 @@
+use super::point_attractor::{AttractorSource, Falloff, PointAttractor};
+use common::math::vectors::Vec2;
+use std::rc::Rc;
+
+fn fixed(falloff: Falloff) -> PointAttractor {
+    PointAttractor::new(AttractorSource::Fixed(Vec2::zeros()), falloff, 100.0)
+}
+
+#[test]
+fn test_inverse_square_decreases_with_distance() {
+    let attractor = fixed(Falloff::InverseSquare);
+    let near = attractor.acceleration(Vec2::zeros(), Vec2::new(5.0, 0.0));
+    let far = attractor.acceleration(Vec2::zeros(), Vec2::new(10.0, 0.0));
+
+    assert!((near.x + 4.0).abs() < 1e-5);
+    assert!((far.x + 1.0).abs() < 1e-5);
+}
+
+#[test]
+fn test_falloff_laws() {
+    let pos = Vec2::new(0.0, 4.0);
+
+    assert!((fixed(Falloff::LinearSpring).acceleration(Vec2::zeros(), pos).y + 400.0).abs() < 1e-3);
+    assert!((fixed(Falloff::Constant).acceleration(Vec2::zeros(), pos).y + 100.0).abs() < 1e-3);
+    assert!((fixed(Falloff::Custom(Rc::new(|r| r * 0.5))).acceleration(Vec2::zeros(), pos).y + 200.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_radius_clamps_and_repel() {
+    let attractor = fixed(Falloff::InverseSquare).with_radius(10.0, 50.0);
+
+    // Inside the minimum radius the pull is capped at the value it has there
+    let inside = attractor.acceleration(Vec2::zeros(), Vec2::new(1.0, 0.0));
+    assert!((inside.x + 1.0).abs() < 1e-5);
+
+    let outside = attractor.acceleration(Vec2::zeros(), Vec2::new(60.0, 0.0));
+    assert_eq!(outside.x, 0.0);
+
+    let repel = fixed(Falloff::Constant).with_repel(true);
+    assert!(repel.acceleration(Vec2::zeros(), Vec2::new(3.0, 0.0)).x > 0.0);
+}
@@ -0,0 +1,71 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+use macroquad::color::Color;
+use macroquad::shapes::{draw_line, draw_rectangle};
+
+const ZONE_COLOR: Color = Color::new(0.9, 0.9, 0.3, 0.15);
+const ARROW_COLOR: Color = Color::new(0.9, 0.9, 0.3, 0.6);
+
+/// Constant acceleration for every body overlapping a box, like a fan or a current.
+#[derive(Clone)]
+pub struct PushZone {
+    pub selector: Selector,
+    pub min: Vec2,
+    pub max: Vec2,
+    pub acceleration: Vec2,
+}
+
+impl PushZone {
+    pub fn new(min: Vec2, max: Vec2, acceleration: Vec2) -> Self {
+        Self {
+            selector: Selector::rectangle(min, max),
+            min,
+            max,
+            acceleration,
+        }
+    }
+}
+
+impl Effector for PushZone {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        for_each_dynamic(targets, |entity| {
+            let f = self.acceleration * entity.get_mass();
+            entity.apply_force(f);
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn render(&self) {
+        let size = self.max - self.min;
+        draw_rectangle(self.min.x, self.min.y, size.x, size.y, ZONE_COLOR);
+
+        let length = self.acceleration.length();
+        if length > 0.0 {
+            let center = (self.min + self.max) * 0.5;
+            let tip = center + self.acceleration / length * (size.x.min(size.y) * 0.4);
+            draw_line(center.x, center.y, tip.x, tip.y, 3.0, ARROW_COLOR);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
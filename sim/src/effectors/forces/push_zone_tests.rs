@@ -0,0 +1,22 @@
+use super::push_zone::PushZone;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::Entity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_push_zone_only_acts_inside() {
+    let mut world = World::new(1000.0, 1000.0);
+    let inside = Rc::new(RefCell::new(CircleEntity::new(150.0, 150.0)));
+    let outside = Rc::new(RefCell::new(CircleEntity::new(400.0, 150.0)));
+    world.add_entity(inside.clone());
+    world.add_entity(outside.clone());
+
+    world.add_effector(Box::new(PushZone::new(Vec2::new(100.0, 100.0), Vec2::new(200.0, 200.0), Vec2::new(0.0, -50.0))));
+    world.update(0.1);
+
+    assert_eq!(inside.borrow().get_force().y, -100.0);
+    assert_eq!(outside.borrow().get_force().length(), 0.0);
+}
@@ -0,0 +1,106 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::EntityType;
+use crate::world::{EntityHandle, EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+use macroquad::color::ORANGE;
+use macroquad::shapes::draw_line;
+
+/// Length of the drawn flame at full throttle.
+const FLAME_LENGTH: f32 = 20.0;
+
+/// Engine mounted on an entity. Pushes along a direction fixed to the body, from a point
+/// fixed to the body, so thrusters away from the centre also turn it. The selector is unused,
+/// a thruster only ever pushes the entity it is mounted on.
+#[derive(Clone)]
+pub struct Thruster {
+    pub selector: Selector,
+    pub entity: EntityHandle,
+    /// Mounting point relative to the entity's centre, before rotation.
+    pub offset: Vec2,
+    /// Unit direction of the force on the entity, before rotation.
+    pub direction: Vec2,
+    pub max_thrust: f32,
+    /// Fraction of the maximum thrust currently applied, between zero and one.
+    pub throttle: f32,
+    /// World space mounting point and force direction from the last update, for drawing.
+    flame: Option<(Vec2, Vec2)>,
+    /// Set once the entity is gone, the thruster is then removed from the world.
+    orphaned: bool,
+}
+
+impl Thruster {
+    pub fn new(entity: EntityHandle, offset: Vec2, direction: Vec2, max_thrust: f32) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            entity,
+            offset,
+            direction: direction.unit(),
+            max_thrust,
+            throttle: 0.0,
+            flame: None,
+            orphaned: false,
+        }
+    }
+
+    pub fn set_throttle(&mut self, throttle: f32) {
+        self.throttle = throttle.clamp(0.0, 1.0);
+    }
+}
+
+impl Effector for Thruster {
+    fn update(&mut self, _targets: &[EntityRef], entities: &EntityMap, _delta: f32) {
+        self.flame = None;
+
+        let Some(entity) = entities.get(&self.entity) else {
+            self.orphaned = true;
+            return;
+        };
+        let mut entity = entity.borrow_mut();
+        if !matches!(entity.get_type(), EntityType::Dynamic) {
+            return;
+        }
+
+        let angle = entity.get_angle();
+        let point = entity.get_center() + self.offset.rotate(angle);
+        let direction = self.direction.rotate(angle);
+
+        if self.throttle > 0.0 {
+            entity.apply_force_at(direction * (self.max_thrust * self.throttle), point);
+            self.flame = Some((point, direction));
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.orphaned
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn render(&self) {
+        if let Some((point, direction)) = self.flame {
+            // The exhaust leaves opposite to the push
+            let tip = point - direction * (FLAME_LENGTH * self.throttle);
+            draw_line(point.x, point.y, tip.x, tip.y, 4.0, ORANGE);
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,75 @@
+use super::thruster::Thruster;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::Entity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::f32::consts::FRAC_PI_2;
+use std::rc::Rc;
+
+#[test]
+fn test_thruster_follows_body_rotation() {
+    let mut world = World::new(1000.0, 1000.0);
+    let ship = Rc::new(RefCell::new(CircleEntity::new(500.0, 500.0)));
+    ship.borrow_mut().angle = FRAC_PI_2;
+    let handle = world.add_entity(ship.clone());
+
+    let mut thruster = Thruster::new(handle, Vec2::new(0.0, 10.0), Vec2::new(0.0, -1.0), 100.0);
+    thruster.set_throttle(2.0);
+    world.add_effector(Box::new(thruster));
+    world.update(0.1);
+
+    // Rotated a quarter turn the engine pushes along +x, in line with the centre so without torque
+    let ship = ship.borrow();
+    assert!((ship.get_force().x - 100.0).abs() < 1e-3);
+    assert!(ship.get_force().y.abs() < 1e-3);
+    assert!(ship.get_torque().abs() < 1e-3);
+}
+
+#[test]
+fn test_offset_thruster_turns_body() {
+    let mut world = World::new(1000.0, 1000.0);
+    let ship = Rc::new(RefCell::new(CircleEntity::new(500.0, 500.0)));
+    let handle = world.add_entity(ship.clone());
+
+    let mut thruster = Thruster::new(handle, Vec2::new(0.0, -10.0), Vec2::new(1.0, 0.0), 100.0);
+    thruster.set_throttle(0.5);
+    world.add_effector(Box::new(thruster));
+    world.update(0.1);
+
+    assert_eq!(ship.borrow().get_force().x, 50.0);
+    assert_eq!(ship.borrow().get_torque(), 500.0);
+}
+
+#[test]
+fn test_integrator_clamps_force_and_speed() {
+    let mut body = CircleEntity::new(0.0, 0.0);
+    body.max_force = 10.0;
+    body.max_linear_speed = 3.0;
+
+    body.apply_force(Vec2::new(0.0, 1000.0));
+    body.update(0.1);
+    assert!((body.get_velocity().y - 0.5).abs() < 1e-5);
+
+    for _ in 0..100 {
+        body.apply_force(Vec2::new(10.0, 0.0));
+        body.update(0.1);
+    }
+    assert!(body.get_velocity().length() <= 3.0 + 1e-5);
+}
+
+#[test]
+fn test_thruster_is_removed_with_its_entity() {
+    let mut world = World::new(1000.0, 1000.0);
+    let ship = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(500.0, 500.0))));
+    let thruster = world.add_effector(Box::new(Thruster::new(ship, Vec2::zeros(), Vec2::new(0.0, -1.0), 100.0)));
+
+    world.step(0.1);
+    assert!(world.get_effector_mut(thruster).is_some());
+
+    // Flying out of the world removes the ship, and its thruster goes on the next step
+    world.get_entity(ship).unwrap().borrow_mut().get_position_mut().x = -100.0;
+    world.step(0.1);
+    assert!(world.get_entity(ship).is_none());
+    assert!(world.get_effector_mut(thruster).is_none());
+}
@@ -0,0 +1,62 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::entity::EntityType;
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+/// Constant acceleration, scaled per entity by its gravity scale.
+/// Applies to every dynamic entity in the world unless given another selector.
+#[derive(Clone)]
+pub struct UniformGravity {
+    pub selector: Selector,
+    pub gravity: Vec2,
+}
+
+impl UniformGravity {
+    pub fn new(gravity: Vec2) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            gravity,
+        }
+    }
+}
+
+impl Effector for UniformGravity {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        let gravity = self.gravity;
+
+        for_each_dynamic(targets, |entity| {
+            let f = gravity * entity.get_mass() * entity.get_gravity_scale();
+            entity.apply_force(f);
+        });
+    }
+
+    fn potential_energy(&self, targets: &[EntityRef]) -> f32 {
+        targets.iter()
+            .map(|e| e.borrow())
+            .filter(|e| matches!(e.get_type(), EntityType::Dynamic))
+            .map(|e| -e.get_mass() * e.get_gravity_scale() * self.gravity.dot(&e.get_center()))
+            .sum()
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,27 @@
+use super::uniform_gravity::UniformGravity;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_uniform_gravity_respects_gravity_scale() {
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 100.0))));
+
+    let normal = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+
+    let mut floating = CircleEntity::new(300.0, 100.0);
+    floating.gravity_scale = 0.0;
+    let floating = world.add_entity(Rc::new(RefCell::new(floating)));
+
+    let ground = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(500.0, 100.0, 10.0, 10.0))));
+
+    world.step(0.1);
+
+    assert!(world.get_entity(normal).unwrap().borrow().get_velocity().y > 9.0);
+    assert_eq!(world.get_entity(floating).unwrap().borrow().get_velocity().y, 0.0);
+    assert_eq!(world.get_entity(ground).unwrap().borrow().get_position().y, 100.0);
+}
@@ -0,0 +1,84 @@
+use std::any::Any;
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+use macroquad::color::Color;
+use macroquad::shapes::draw_circle_lines;
+
+const VORTEX_COLOR: Color = Color::new(0.5, 0.8, 1.0, 0.4);
+
+/// Swirl around a point. Both the tangential and the inward acceleration fade
+/// linearly from full strength at the centre to nothing at the radius.
+#[derive(Clone)]
+pub struct Vortex {
+    pub selector: Selector,
+    pub center: Vec2,
+    /// Tangential acceleration at the centre, positive values swirl clockwise on screen.
+    pub strength: f32,
+    /// Acceleration towards the centre at the centre.
+    pub pull: f32,
+    pub radius: f32,
+}
+
+impl Vortex {
+    pub fn new(center: Vec2, strength: f32, radius: f32) -> Self {
+        Self {
+            selector: Selector::circle(center, radius),
+            center,
+            strength,
+            pull: 0.0,
+            radius,
+        }
+    }
+
+    pub fn with_pull(mut self, pull: f32) -> Self {
+        self.pull = pull;
+        self
+    }
+
+    pub fn acceleration(&self, pos: Vec2) -> Vec2 {
+        let d = pos - self.center;
+        let r = d.length();
+        if r == 0.0 || r > self.radius {
+            return Vec2::zeros();
+        }
+
+        let radial = d / r;
+        let fade = 1.0 - r / self.radius;
+        (radial.perp() * self.strength - radial * self.pull) * fade
+    }
+}
+
+impl Effector for Vortex {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        for_each_dynamic(targets, |entity| {
+            let f = self.acceleration(entity.get_center()) * entity.get_mass();
+            entity.apply_force(f);
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn render(&self) {
+        draw_circle_lines(self.center.x, self.center.y, self.radius, 2.0, VORTEX_COLOR);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,18 @@
+use super::vortex::Vortex;
+use common::math::vectors::Vec2;
+
+#[test]
+fn test_vortex_swirls_with_radial_falloff() {
+    let vortex = Vortex::new(Vec2::zeros(), 100.0, 200.0);
+
+    let near = vortex.acceleration(Vec2::new(50.0, 0.0));
+    assert!(near.x.abs() < 1e-5);
+    assert!((near.y - 75.0).abs() < 1e-4);
+
+    let far = vortex.acceleration(Vec2::new(0.0, 150.0));
+    assert!((far.x + 25.0).abs() < 1e-4);
+    assert_eq!(vortex.acceleration(Vec2::new(300.0, 0.0)).length(), 0.0);
+
+    let pulling = Vortex::new(Vec2::zeros(), 0.0, 200.0).with_pull(40.0);
+    assert!((pulling.acceleration(Vec2::new(100.0, 0.0)).x + 20.0).abs() < 1e-4);
+}
@@ -0,0 +1,196 @@
+use std::any::Any;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::effectors::forces::drag::{Drag, DragCoefficients};
+use crate::effectors::selector::Selector;
+use crate::effectors::{for_each_dynamic, Effector};
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+#[derive(Clone)]
+pub enum WindSource {
+    Uniform(Vec2),
+    /// Smooth value noise drifting over time, added on top of a base wind.
+    Noise { base: Vec2, amplitude: f32, scale: f32, speed: f32 },
+    /// Air velocities on a regular grid, bilinearly interpolated and clamped at the edges.
+    Grid { origin: Vec2, cell_size: f32, cols: usize, rows: usize, cells: Vec<Vec2> },
+}
+
+impl WindSource {
+    /// Loads a grid from a text file. The first line holds `cols rows cell_size [origin_x origin_y]`,
+    /// followed by one line per row of whitespace separated `vx,vy` pairs. Lines starting with `#` are skipped.
+    pub fn load_grid(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse_grid(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse_grid(text: &str) -> io::Result<Self> {
+        let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+        let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with('#'));
+
+        let header: Vec<f32> = lines.next()
+            .ok_or_else(|| invalid("missing wind grid header"))?
+            .split_whitespace()
+            .map(|v| v.parse().map_err(|_| invalid("invalid wind grid header")))
+            .collect::<io::Result<_>>()?;
+
+        let (cols, rows, cell_size) = match header[..] {
+            [cols, rows, cell_size, ..] if cols >= 1.0 && rows >= 1.0 && cell_size > 0.0 => {
+                (cols as usize, rows as usize, cell_size)
+            }
+            _ => return Err(invalid("wind grid header needs cols, rows and a positive cell size")),
+        };
+        let origin = match header[..] {
+            [_, _, _, x, y] => Vec2::new(x, y),
+            _ => Vec2::zeros(),
+        };
+
+        let mut cells = Vec::with_capacity(cols * rows);
+        for line in lines.by_ref().take(rows) {
+            let row: Vec<Vec2> = line.split_whitespace()
+                .map(|pair| {
+                    let (x, y) = pair.split_once(',').ok_or_else(|| invalid("wind cells must be `vx,vy` pairs"))?;
+                    match (x.parse(), y.parse()) {
+                        (Ok(x), Ok(y)) => Ok(Vec2::new(x, y)),
+                        _ => Err(invalid("invalid wind cell")),
+                    }
+                })
+                .collect::<io::Result<_>>()?;
+
+            if row.len() != cols {
+                return Err(invalid("wind grid row has the wrong number of cells"));
+            }
+            cells.extend(row);
+        }
+
+        if cells.len() != cols * rows {
+            return Err(invalid("wind grid has the wrong number of rows"));
+        }
+
+        Ok(WindSource::Grid { origin, cell_size, cols, rows, cells })
+    }
+
+    /// Air velocity at `pos` at the given time.
+    pub fn sample(&self, pos: Vec2, time: f32) -> Vec2 {
+        match self {
+            WindSource::Uniform(v) => *v,
+            WindSource::Noise { base, amplitude, scale, speed } => {
+                let (x, y, t) = (pos.x * scale, pos.y * scale, time * speed);
+                *base + Vec2::new(value_noise(x, y, t, 0), value_noise(x, y, t, 1)) * *amplitude
+            }
+            WindSource::Grid { origin, cell_size, cols, rows, cells } => {
+                let local = (pos - *origin) / *cell_size;
+                let x = local.x.clamp(0.0, (*cols - 1) as f32);
+                let y = local.y.clamp(0.0, (*rows - 1) as f32);
+
+                let (x0, y0) = (x.floor() as usize, y.floor() as usize);
+                let (x1, y1) = ((x0 + 1).min(cols - 1), (y0 + 1).min(rows - 1));
+                let (tx, ty) = (x - x0 as f32, y - y0 as f32);
+
+                let cell = |cx: usize, cy: usize| cells[cy * cols + cx];
+                let top = cell(x0, y0) * (1.0 - tx) + cell(x1, y0) * tx;
+                let bottom = cell(x0, y1) * (1.0 - tx) + cell(x1, y1) * tx;
+                top * (1.0 - ty) + bottom * ty
+            }
+        }
+    }
+}
+
+/// Pseudo-random value in `[-1, 1]` for an integer lattice point.
+fn lattice(x: i32, y: i32, t: i32, channel: u32) -> f32 {
+    let mut h = (x as u32).wrapping_mul(0x8da6_b343)
+        ^ (y as u32).wrapping_mul(0xd816_3841)
+        ^ (t as u32).wrapping_mul(0xcb1a_b31f)
+        ^ channel.wrapping_mul(0x1656_67b1);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0x5bd1_e995);
+    h ^= h >> 15;
+
+    (h & 0xffff) as f32 / 32767.5 - 1.0
+}
+
+/// Trilinear value noise with smoothstep easing.
+fn value_noise(x: f32, y: f32, t: f32, channel: u32) -> f32 {
+    let (x0, y0, t0) = (x.floor(), y.floor(), t.floor());
+    let smooth = |v: f32| v * v * (3.0 - 2.0 * v);
+    let (sx, sy, st) = (smooth(x - x0), smooth(y - y0), smooth(t - t0));
+    let (xi, yi, ti) = (x0 as i32, y0 as i32, t0 as i32);
+    let lerp = |a: f32, b: f32, s: f32| a + (b - a) * s;
+
+    let layer = |t: i32| {
+        let top = lerp(lattice(xi, yi, t, channel), lattice(xi + 1, yi, t, channel), sx);
+        let bottom = lerp(lattice(xi, yi + 1, t, channel), lattice(xi + 1, yi + 1, t, channel), sx);
+        lerp(top, bottom, sy)
+    };
+
+    lerp(layer(ti), layer(ti + 1), st)
+}
+
+/// Moving air, so bodies feel drag against their velocity relative to the local wind.
+#[derive(Clone)]
+pub struct WindField {
+    pub selector: Selector,
+    pub source: WindSource,
+    pub coefficients: DragCoefficients,
+    /// Set when a `Drag` effector with the same coefficients already slows the targets through still air,
+    /// so only the difference the wind makes is applied on top of it.
+    pub over_drag: bool,
+    time: f32,
+}
+
+impl WindField {
+    pub fn new(source: WindSource, coefficients: DragCoefficients) -> Self {
+        Self {
+            selector: Selector::AllDynamic,
+            source,
+            coefficients,
+            over_drag: false,
+            time: 0.0,
+        }
+    }
+
+    /// Wind blowing over `drag`, with its coefficients and acting on the bodies it selects.
+    pub fn with_drag(source: WindSource, drag: &Drag) -> Self {
+        Self {
+            selector: drag.selector.clone(),
+            over_drag: true,
+            ..Self::new(source, drag.coefficients)
+        }
+    }
+}
+
+impl Effector for WindField {
+    fn update(&mut self, targets: &[EntityRef], _entities: &EntityMap, delta: f32) {
+        self.time += delta;
+        let (source, coefficients, time, over_drag) = (&self.source, self.coefficients, self.time, self.over_drag);
+
+        for_each_dynamic(targets, |entity| {
+            let wind = source.sample(entity.get_center(), time);
+            let mut f = coefficients.force(entity, wind);
+            if over_drag {
+                f -= coefficients.force(entity, Vec2::zeros());
+            }
+            entity.apply_force(f);
+        });
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
@@ -1,22 +1,57 @@
 pub mod forces;
+pub mod selector;
+#[cfg(test)]
+mod selector_tests;
 
 use std::any::Any;
-use crate::world::WeakEntityRef;
+use crate::effectors::selector::Selector;
+use crate::entity::{Entity, EntityType};
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
 
 pub trait Effector : Any {
 
-    fn update(&mut self, delta: f32);
+    /// `targets` are the entities picked by the selector this step, `entities` the whole world.
+    fn update(&mut self, targets: &[EntityRef], entities: &EntityMap, delta: f32);
 
-    fn get_entities(&self) -> &Vec<WeakEntityRef>;
-    fn get_entities_mut(&mut self) -> &mut Vec<WeakEntityRef>;
+    fn get_selector(&self) -> &Selector;
+    fn get_selector_mut(&mut self) -> &mut Selector;
 
-    fn add_entity(&mut self, entity_ref: WeakEntityRef) {
-        self.get_entities_mut().push(entity_ref);
+    fn with_selector(mut self, selector: Selector) -> Self where Self: Sized {
+        *self.get_selector_mut() = selector;
+        self
     }
 
-    fn cleanup(&mut self) {
-        self.get_entities_mut().retain(|weak_entity| weak_entity.strong_count() > 0);
+    /// Expired effectors are removed from the world after their update.
+    fn is_expired(&self) -> bool {
+        false
     }
 
+    /// Potential energy of the targets in this effector's field, for effectors whose force is conservative.
+    /// Zero for the rest, whose work is not stored anywhere.
+    fn potential_energy(&self, _targets: &[EntityRef]) -> f32 {
+        0.0
+    }
+
+    /// Draws the effector's area of influence, if it has one.
+    fn render(&self) {}
+
+    /// Receives the pointer position from the world, for effectors that follow the mouse.
+    fn set_pointer(&mut self, _pointer: Vec2) {}
+
     fn as_any(&self) -> &dyn Any;
-}
\ No newline at end of file
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Owned copy including any internal state, used by world snapshots.
+    fn clone_box(&self) -> Box<dyn Effector>;
+}
+
+/// Runs `apply` on the dynamic entities among `targets`, static ones are never pushed around.
+pub(crate) fn for_each_dynamic(targets: &[EntityRef], mut apply: impl FnMut(&mut dyn Entity)) {
+    for entity in targets {
+        let mut entity = entity.borrow_mut();
+        if matches!(entity.get_type(), EntityType::Dynamic) {
+            apply(&mut *entity);
+        }
+    }
+}
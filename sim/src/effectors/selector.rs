@@ -0,0 +1,56 @@
+use crate::collisions::colliders::{CircleBB, RectangleBB};
+use crate::collisions::{BoundBox, Collider};
+use crate::entity::{Entity, EntityType};
+use crate::world::{EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+/// Which entities an effector acts on. Resolved by the world before every update,
+/// so bodies join and leave as they spawn, change group or move through a region.
+#[derive(Clone)]
+pub enum Selector {
+    AllDynamic,
+    /// Entities whose groups share a bit with the mask.
+    Groups(u32),
+    Tag(String),
+    /// Entities overlapping a sensor collider placed at `position`.
+    Region { collider: Collider, position: Vec2 },
+}
+
+impl Selector {
+    /// Region selector for a circle of `radius` around `center`.
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Selector::Region {
+            collider: Collider { rel_pos: Vec2::zeros(), bound_box: BoundBox::Circle(CircleBB { radius }) },
+            position: center,
+        }
+    }
+
+    /// Region selector for the axis aligned box spanning `min` to `max`.
+    pub fn rectangle(min: Vec2, max: Vec2) -> Self {
+        let size = max - min;
+
+        Selector::Region {
+            collider: Collider {
+                rel_pos: Vec2::zeros(),
+                bound_box: BoundBox::Rectangle(RectangleBB { width: size.x, height: size.y }),
+            },
+            position: min,
+        }
+    }
+
+    pub fn matches(&self, entity: &dyn Entity) -> bool {
+        match self {
+            Selector::AllDynamic => matches!(entity.get_type(), EntityType::Dynamic),
+            Selector::Groups(mask) => entity.get_groups() & mask != 0,
+            Selector::Tag(tag) => entity.get_tags().iter().any(|t| t == tag),
+            Selector::Region { collider, position } => collider
+                .detect_collision(position, entity.get_collider(), &entity.get_position())
+                .is_some(),
+        }
+    }
+
+    /// Matching entities, in handle order.
+    pub fn resolve(&self, entities: &EntityMap) -> Vec<EntityRef> {
+        entities.values().filter(|e| self.matches(&*e.borrow())).cloned().collect()
+    }
+}
@@ -0,0 +1,53 @@
+use super::selector::Selector;
+use crate::collisions::colliders::RectangleBB;
+use crate::collisions::{BoundBox, Collider};
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn world_with_bodies() -> World {
+    let mut world = World::new(1000.0, 1000.0);
+
+    let mut tagged = CircleEntity::new(100.0, 100.0);
+    tagged.tags.push("marked".to_string());
+    tagged.groups = 1 << 1;
+    world.add_entity(Rc::new(RefCell::new(tagged)));
+
+    world.add_entity(Rc::new(RefCell::new(CircleEntity::new(500.0, 500.0))));
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 0.0, 10.0, 10.0))));
+    world
+}
+
+#[test]
+fn test_selectors_pick_matching_entities() {
+    let world = world_with_bodies();
+
+    assert_eq!(Selector::AllDynamic.resolve(&world.entities).len(), 2);
+    assert_eq!(Selector::Groups(1).resolve(&world.entities).len(), 2);
+    assert_eq!(Selector::Groups(0b110).resolve(&world.entities).len(), 1);
+    assert_eq!(Selector::Tag("marked".to_string()).resolve(&world.entities).len(), 1);
+    assert!(Selector::Tag("missing".to_string()).resolve(&world.entities).is_empty());
+}
+
+#[test]
+fn test_region_membership_follows_entities() {
+    let mut world = world_with_bodies();
+    let region = Selector::Region {
+        collider: Collider { rel_pos: Vec2::zeros(), bound_box: BoundBox::Rectangle(RectangleBB { width: 100.0, height: 100.0 }) },
+        position: Vec2::new(450.0, 450.0),
+    };
+
+    let inside = region.resolve(&world.entities);
+    assert_eq!(inside.len(), 1);
+    assert_eq!(inside[0].borrow().get_position().x, 500.0);
+
+    // Moving the body out of the sensor drops it on the next resolve
+    inside[0].borrow_mut().get_position_mut().x = 800.0;
+    assert!(region.resolve(&world.entities).is_empty());
+
+    world.add_entity(Rc::new(RefCell::new(CircleEntity::new(460.0, 460.0))));
+    assert_eq!(region.resolve(&world.entities).len(), 1);
+}
@@ -1,17 +1,29 @@
+use std::cell::RefCell;
+use std::rc::Rc;
 use crate::collisions::colliders::CircleBB;
 use crate::collisions::BoundBox::Circle;
 use crate::collisions::Collider;
-use crate::entity::{Entity, EntityType};
-use crate::world::World;
+use crate::entity::{draw_charge, Entity, EntityType};
+use crate::world::{EntityRef, World};
 use common::math::vectors::Vec2;
-use macroquad::color::GREEN;
-use macroquad::shapes::draw_circle;
+use macroquad::color::{DARKGREEN, GREEN};
+use macroquad::shapes::{draw_circle, draw_line};
 
+#[derive(Clone)]
 pub struct CircleEntity {
     pub position: Vec2,
     force: Vec2,
     pub velocity: Vec2,
+    pub angle: f32,
+    pub angular_velocity: f32,
+    torque: f32,
     pub mass: f32,
+    pub gravity_scale: f32,
+    pub max_linear_speed: f32,
+    pub max_force: f32,
+    pub charge: f32,
+    pub groups: u32,
+    pub tags: Vec<String>,
     pub collider: Collider,
     pub entity_type: EntityType
 }
@@ -22,7 +34,16 @@ impl CircleEntity {
             position: Vec2{x,y},
             force: Vec2::zeros(),
             velocity: Vec2::zeros(),
+            angle: 0.0,
+            angular_velocity: 0.0,
+            torque: 0.0,
             mass: 2.0,
+            gravity_scale: 1.0,
+            max_linear_speed: f32::INFINITY,
+            max_force: f32::INFINITY,
+            charge: 0.0,
+            groups: 1,
+            tags: vec![],
             collider: Collider {
                 rel_pos: Vec2::zeros(),
                 bound_box: Circle(CircleBB {
@@ -36,11 +57,20 @@ impl CircleEntity {
 
 impl Entity for CircleEntity {
     fn render(&self, _world: &World) {
-        draw_circle(self.position.x, self.position.y, 10.0, GREEN)
+        let radius = match &self.collider.bound_box {
+            Circle(circle) => circle.radius,
+            _ => 10.0,
+        };
+        draw_circle(self.position.x, self.position.y, radius, GREEN);
+
+        let spoke = self.position + Vec2::new(radius, 0.0).rotate(self.angle);
+        draw_line(self.position.x, self.position.y, spoke.x, spoke.y, 2.0, DARKGREEN);
+
+        draw_charge(self.position, self.charge);
     }
 
     fn get_position(&self) -> Vec2 {
-        self.position.clone()
+        self.position
     }
 
     fn get_position_mut(&mut self) -> &mut Vec2 {
@@ -63,6 +93,30 @@ impl Entity for CircleEntity {
         &mut self.force
     }
 
+    fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    fn get_angle_mut(&mut self) -> &mut f32 {
+        &mut self.angle
+    }
+
+    fn get_angular_velocity(&self) -> f32 {
+        self.angular_velocity
+    }
+
+    fn get_angular_velocity_mut(&mut self) -> &mut f32 {
+        &mut self.angular_velocity
+    }
+
+    fn get_torque(&self) -> f32 {
+        self.torque
+    }
+
+    fn get_torque_mut(&mut self) -> &mut f32 {
+        &mut self.torque
+    }
+
     fn get_collider(&self) -> &Collider {
         &self.collider
     }
@@ -71,7 +125,35 @@ impl Entity for CircleEntity {
         self.mass
     }
 
+    fn get_gravity_scale(&self) -> f32 {
+        self.gravity_scale
+    }
+
+    fn get_max_linear_speed(&self) -> f32 {
+        self.max_linear_speed
+    }
+
+    fn get_max_force(&self) -> f32 {
+        self.max_force
+    }
+
+    fn get_charge(&self) -> f32 {
+        self.charge
+    }
+
+    fn get_groups(&self) -> u32 {
+        self.groups
+    }
+
+    fn get_tags(&self) -> &[String] {
+        &self.tags
+    }
+
     fn get_type(&self) -> &EntityType {
         &self.entity_type
     }
+
+    fn clone_entity(&self) -> EntityRef {
+        Rc::new(RefCell::new(self.clone()))
+    }
 }
\ No newline at end of file
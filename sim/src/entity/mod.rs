@@ -2,9 +2,14 @@ pub mod circle_entity;
 pub mod rectangle_entity;
 
 use crate::collisions::Collider;
-use crate::world::World;
+use crate::world::{EntityRef, World};
 use common::math::vectors::Vec2;
+use macroquad::color::WHITE;
+use macroquad::shapes::draw_line;
 
+const CHARGE_MARK_SIZE: f32 = 4.0;
+
+#[derive(Clone)]
 pub enum EntityType {
     Dynamic,
     Static
@@ -16,18 +21,89 @@ pub trait Entity {
     fn apply_force(&mut self, force: Vec2) {
         *self.get_force_mut() += force;
     }
+
+    /// Applies a force at a world space point, producing torque around the centre.
+    fn apply_force_at(&mut self, force: Vec2, point: Vec2) {
+        let r = point - self.get_center();
+        *self.get_force_mut() += force;
+        *self.get_torque_mut() += r.cross(&force);
+    }
+
+    /// Instantly changes the velocity by `impulse` over the mass.
+    fn apply_impulse(&mut self, impulse: Vec2) {
+        let inv_mass = self.get_inverse_mass();
+        *self.get_velocity_mut() += impulse * inv_mass;
+    }
+
+    fn apply_torque(&mut self, torque: f32) {
+        *self.get_torque_mut() += torque;
+    }
     
     fn update(&mut self, delta_time: f32) {
-        let vel = self.get_velocity();
-        let d = (vel * -1.0) * 0.01;
-        let f = d + self.get_force();
+        // Joints push on both ends regardless of type, so whatever reached a static entity is dropped here
+        if let EntityType::Static = self.get_type() {
+            *self.get_force_mut() = Vec2::zeros();
+            *self.get_torque_mut() = 0.0;
+            return;
+        }
 
-        let a = f / self.get_mass();
+        let vel = self.get_velocity();
+        let a = self.get_force().clamp_length(self.get_max_force()) / self.get_mass();
 
         *self.get_position_mut() += vel * delta_time + 0.5 * a * delta_time * delta_time;
-        *self.get_velocity_mut() += a * delta_time;
+        *self.get_velocity_mut() = (vel + a * delta_time).clamp_length(self.get_max_linear_speed());
+
+        let ang_vel = self.get_angular_velocity();
+        let alpha = self.get_torque() / self.get_inertia();
+
+        *self.get_angle_mut() += ang_vel * delta_time + 0.5 * alpha * delta_time * delta_time;
+        *self.get_angular_velocity_mut() += alpha * delta_time;
 
         *self.get_force_mut() = Vec2::zeros();
+        *self.get_torque_mut() = 0.0;
+    }
+
+    fn get_inverse_mass(&self) -> f32 {
+        match self.get_type() {
+            EntityType::Dynamic => 1.0 / self.get_mass(),
+            EntityType::Static => 0.0
+        }
+    }
+
+    fn get_inertia(&self) -> f32 {
+        self.get_collider().moment_of_inertia(self.get_mass())
+    }
+
+    fn get_inverse_inertia(&self) -> f32 {
+        match self.get_type() {
+            EntityType::Dynamic => 1.0 / self.get_inertia(),
+            EntityType::Static => 0.0
+        }
+    }
+
+    /// Translational plus rotational kinetic energy, zero for static entities.
+    fn get_kinetic_energy(&self) -> f32 {
+        match self.get_type() {
+            EntityType::Dynamic => {
+                0.5 * self.get_mass() * self.get_velocity().dot(&self.get_velocity())
+                    + 0.5 * self.get_inertia() * self.get_angular_velocity() * self.get_angular_velocity()
+            }
+            EntityType::Static => 0.0
+        }
+    }
+
+    /// Mass per unit of collider area.
+    fn get_density(&self) -> f32 {
+        self.get_mass() / self.get_collider().area()
+    }
+
+    fn get_center(&self) -> Vec2 {
+        self.get_collider().centroid(&self.get_position())
+    }
+
+    /// Velocity of a world space point rigidly attached to the entity.
+    fn get_point_velocity(&self, point: Vec2) -> Vec2 {
+        self.get_velocity() + (point - self.get_center()).perp() * self.get_angular_velocity()
     }
 
     fn get_position(&self) -> Vec2;
@@ -36,7 +112,41 @@ pub trait Entity {
     fn get_velocity_mut(&mut self) -> &mut Vec2;
     fn get_force(&self) -> Vec2;
     fn get_force_mut(&mut self) -> &mut Vec2;
+    fn get_angle(&self) -> f32;
+    fn get_angle_mut(&mut self) -> &mut f32;
+    fn get_angular_velocity(&self) -> f32;
+    fn get_angular_velocity_mut(&mut self) -> &mut f32;
+    fn get_torque(&self) -> f32;
+    fn get_torque_mut(&mut self) -> &mut f32;
     fn get_collider(&self) -> &Collider;
     fn get_mass(&self) -> f32;
+    /// Multiplier applied to uniform gravity, zero makes the entity float.
+    fn get_gravity_scale(&self) -> f32;
+    /// Speed the integrator never lets the entity exceed.
+    fn get_max_linear_speed(&self) -> f32;
+    /// Upper bound on the magnitude of the net force applied in a step.
+    fn get_max_force(&self) -> f32;
+    /// Electric charge, zero for neutral entities.
+    fn get_charge(&self) -> f32;
+    /// Bits of the groups the entity belongs to, matched against selector masks. Collisions ignore them.
+    fn get_groups(&self) -> u32;
+    fn get_tags(&self) -> &[String];
     fn get_type(&self) -> &EntityType;
+
+    /// Detached copy of the entity, used by world snapshots.
+    fn clone_entity(&self) -> EntityRef;
+}
+
+/// Draws a plus or minus sign over charged entities.
+pub(crate) fn draw_charge(center: Vec2, charge: f32) {
+    if charge == 0.0 {
+        return;
+    }
+
+    let s = CHARGE_MARK_SIZE;
+    draw_line(center.x - s, center.y, center.x + s, center.y, 2.0, WHITE);
+
+    if charge > 0.0 {
+        draw_line(center.x, center.y - s, center.x, center.y + s, 2.0, WHITE);
+    }
 }
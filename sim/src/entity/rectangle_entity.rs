@@ -1,17 +1,30 @@
+use std::cell::RefCell;
+use std::rc::Rc;
 use crate::collisions::colliders::RectangleBB;
 use crate::collisions::BoundBox::Rectangle;
 use crate::collisions::Collider;
-use crate::entity::{Entity, EntityType};
-use crate::world::World;
+use crate::entity::{draw_charge, Entity, EntityType};
+use crate::world::{EntityRef, World};
 use common::math::vectors::Vec2;
 use macroquad::color::RED;
-use macroquad::shapes::draw_rectangle;
+use macroquad::math::vec2;
+use macroquad::shapes::{draw_rectangle, draw_rectangle_ex, DrawRectangleParams};
 
+#[derive(Clone)]
 pub struct RectangleEntity {
     pub position: Vec2,
     force: Vec2,
     pub velocity: Vec2,
+    pub angle: f32,
+    pub angular_velocity: f32,
+    torque: f32,
     pub mass: f32,
+    pub gravity_scale: f32,
+    pub max_linear_speed: f32,
+    pub max_force: f32,
+    pub charge: f32,
+    pub groups: u32,
+    pub tags: Vec<String>,
     pub collider: Collider,
     pub entity_type: EntityType,
     pub w: f32,
@@ -25,7 +38,16 @@ impl RectangleEntity {
             position: Vec2{x,y},
             force: Vec2::zeros(),
             velocity: Vec2::zeros(),
+            angle: 0.0,
+            angular_velocity: 0.0,
+            torque: 0.0,
             mass: 2.0,
+            gravity_scale: 1.0,
+            max_linear_speed: f32::INFINITY,
+            max_force: f32::INFINITY,
+            charge: 0.0,
+            groups: 1,
+            tags: vec![],
             collider: Collider {
                 rel_pos: Vec2::zeros(),
                 bound_box: Rectangle(RectangleBB {
@@ -44,7 +66,16 @@ impl RectangleEntity {
             position: Vec2{x,y},
             force: Vec2::zeros(),
             velocity: Vec2::zeros(),
+            angle: 0.0,
+            angular_velocity: 0.0,
+            torque: 0.0,
             mass: 2.0,
+            gravity_scale: 1.0,
+            max_linear_speed: f32::INFINITY,
+            max_force: f32::INFINITY,
+            charge: 0.0,
+            groups: 1,
+            tags: vec![],
             collider: Collider {
                 rel_pos: Vec2::zeros(),
                 bound_box: Rectangle(RectangleBB {
@@ -61,11 +92,24 @@ impl RectangleEntity {
 
 impl Entity for RectangleEntity {
     fn render(&self, _world: &World) {
-        draw_rectangle(self.position.x, self.position.y, self.w, self.h, RED)
+        let center = self.get_center();
+
+        if self.angle == 0.0 {
+            draw_rectangle(self.position.x, self.position.y, self.w, self.h, RED);
+        } else {
+            // Only the drawing is rotated, collisions still use the axis aligned box
+            draw_rectangle_ex(center.x, center.y, self.w, self.h, DrawRectangleParams {
+                offset: vec2(0.5, 0.5),
+                rotation: self.angle,
+                color: RED,
+            });
+        }
+
+        draw_charge(center, self.charge);
     }
 
     fn get_position(&self) -> Vec2 {
-        self.position.clone()
+        self.position
     }
 
     fn get_position_mut(&mut self) -> &mut Vec2 {
@@ -88,6 +132,30 @@ impl Entity for RectangleEntity {
         &mut self.force
     }
 
+    fn get_angle(&self) -> f32 {
+        self.angle
+    }
+
+    fn get_angle_mut(&mut self) -> &mut f32 {
+        &mut self.angle
+    }
+
+    fn get_angular_velocity(&self) -> f32 {
+        self.angular_velocity
+    }
+
+    fn get_angular_velocity_mut(&mut self) -> &mut f32 {
+        &mut self.angular_velocity
+    }
+
+    fn get_torque(&self) -> f32 {
+        self.torque
+    }
+
+    fn get_torque_mut(&mut self) -> &mut f32 {
+        &mut self.torque
+    }
+
     fn get_collider(&self) -> &Collider {
         &self.collider
     }
@@ -96,7 +164,35 @@ impl Entity for RectangleEntity {
         self.mass
     }
 
+    fn get_gravity_scale(&self) -> f32 {
+        self.gravity_scale
+    }
+
+    fn get_max_linear_speed(&self) -> f32 {
+        self.max_linear_speed
+    }
+
+    fn get_max_force(&self) -> f32 {
+        self.max_force
+    }
+
+    fn get_charge(&self) -> f32 {
+        self.charge
+    }
+
+    fn get_groups(&self) -> u32 {
+        self.groups
+    }
+
+    fn get_tags(&self) -> &[String] {
+        &self.tags
+    }
+
     fn get_type(&self) -> &EntityType {
         &self.entity_type
     }
+
+    fn clone_entity(&self) -> EntityRef {
+        Rc::new(RefCell::new(self.clone()))
+    }
 }
\ No newline at end of file
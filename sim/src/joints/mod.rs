@@ -0,0 +1,124 @@
+pub mod mouse;
+pub mod prismatic;
+pub mod revolute;
+pub mod spring;
+pub mod weld;
+#[cfg(test)]
+mod mouse_tests;
+#[cfg(test)]
+mod prismatic_tests;
+#[cfg(test)]
+mod revolute_tests;
+#[cfg(test)]
+mod spring_tests;
+#[cfg(test)]
+mod weld_tests;
+
+use std::any::Any;
+use crate::world::{EntityHandle, EntityMap, EntityRef};
+use common::math::vectors::Vec2;
+
+/// Fraction of the positional error fed back into the velocity solver each step.
+pub(crate) const BAUMGARTE: f32 = 0.2;
+
+/// Drives a joint's free axis towards a target speed without exceeding the given force.
+/// For angular joints the speed is in radians per second and the force is a torque.
+#[derive(Clone)]
+pub struct Motor {
+    pub speed: f32,
+    pub max_force: f32,
+}
+
+pub trait Joint : Any {
+
+    /// Entities this joint connects. The joint is removed once any of them leaves the world.
+    fn get_entities(&self) -> Vec<EntityHandle>;
+
+    /// Called before integration, for joints that act through plain forces.
+    fn apply_forces(&mut self, _entities: &EntityMap, _delta: f32) {}
+
+    /// Called once per step after integration to precompute solver terms.
+    fn prepare(&mut self, _entities: &EntityMap, _delta: f32) {}
+
+    /// Called several times per step to push the connected velocities towards the constraint.
+    fn solve_velocity(&mut self, _entities: &EntityMap) {}
+
+    fn potential_energy(&self, _entities: &EntityMap) -> f32 {
+        0.0
+    }
+
+    fn render(&self, entities: &EntityMap);
+
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Owned copy including the accumulated solver impulses, used by world snapshots.
+    fn clone_box(&self) -> Box<dyn Joint>;
+}
+
+/// Looks up both ends of a joint, skipping joints that connect an entity to itself.
+pub(crate) fn get_pair(entities: &EntityMap, a: EntityHandle, b: EntityHandle) -> Option<(&EntityRef, &EntityRef)> {
+    if a == b {
+        return None;
+    }
+
+    Some((entities.get(&a)?, entities.get(&b)?))
+}
+
+/// Mass matrix `[k11, k12, k22]` of a point-to-point constraint with anchors offset by `r_a` and `r_b`.
+pub(crate) fn point_mass_matrix(m_a: f32, m_b: f32, i_a: f32, i_b: f32, r_a: Vec2, r_b: Vec2) -> [f32; 3] {
+    [
+        m_a + m_b + r_a.y * r_a.y * i_a + r_b.y * r_b.y * i_b,
+        -r_a.y * r_a.x * i_a - r_b.y * r_b.x * i_b,
+        m_a + m_b + r_a.x * r_a.x * i_a + r_b.x * r_b.x * i_b,
+    ]
+}
+
+/// Solves `K·x = rhs` for a symmetric 2x2 matrix, returning zero when it is singular.
+pub(crate) fn solve_2x2(k: [f32; 3], rhs: Vec2) -> Vec2 {
+    let [k11, k12, k22] = k;
+    let det = k11 * k22 - k12 * k12;
+    if det == 0.0 {
+        return Vec2::zeros();
+    }
+
+    Vec2::new(
+        (k22 * rhs.x - k12 * rhs.y) / det,
+        (k11 * rhs.y - k12 * rhs.x) / det,
+    )
+}
+
+/// Coefficients turning a rigid constraint into a damped spring, following Catto's soft step.
+#[derive(Copy, Clone)]
+pub(crate) struct Softness {
+    pub bias_rate: f32,
+    pub mass_scale: f32,
+    pub impulse_scale: f32,
+}
+
+impl Softness {
+    pub fn new(frequency: f32, damping_ratio: f32, delta: f32) -> Self {
+        if frequency <= 0.0 {
+            return Self::rigid(delta);
+        }
+
+        let omega = 2.0 * std::f32::consts::PI * frequency;
+        let a1 = 2.0 * damping_ratio + delta * omega;
+        let a2 = delta * omega * a1;
+        let a3 = 1.0 / (1.0 + a2);
+
+        Self {
+            bias_rate: omega / a1,
+            mass_scale: a2 * a3,
+            impulse_scale: a3,
+        }
+    }
+
+    pub fn rigid(delta: f32) -> Self {
+        Self {
+            bias_rate: BAUMGARTE / delta,
+            mass_scale: 1.0,
+            impulse_scale: 0.0,
+        }
+    }
+}
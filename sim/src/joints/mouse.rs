@@ -0,0 +1,128 @@
+use std::any::Any;
+use crate::joints::{point_mass_matrix, solve_2x2, Joint, Softness};
+use crate::world::{EntityHandle, EntityMap, World};
+use common::math::vectors::Vec2;
+use macroquad::color::LIGHTGRAY;
+use macroquad::shapes::{draw_circle, draw_line};
+
+const FREQUENCY: f32 = 5.0;
+const DAMPING_RATIO: f32 = 0.7;
+
+/// Softly drags a point on an entity towards a movable target, with a bounded force.
+#[derive(Clone)]
+pub struct MouseJoint {
+    pub entity: EntityHandle,
+    pub local_anchor: Vec2,
+    pub target: Vec2,
+    pub max_force: f32,
+    pub frequency: f32,
+    pub damping_ratio: f32,
+    r: Vec2,
+    k: [f32; 3],
+    separation: Vec2,
+    softness: Softness,
+    max_impulse: f32,
+    impulse: Vec2,
+}
+
+impl MouseJoint {
+    /// Grabs `entity` at the world space `point`, which also becomes the initial target.
+    pub fn new(world: &World, entity: EntityHandle, point: Vec2, max_force: f32) -> Self {
+        let local_anchor = match world.get_entity(entity) {
+            Some(e) => {
+                let e = e.borrow();
+                (point - e.get_center()).rotate(-e.get_angle())
+            }
+            None => Vec2::zeros(),
+        };
+
+        Self {
+            entity,
+            local_anchor,
+            target: point,
+            max_force,
+            frequency: FREQUENCY,
+            damping_ratio: DAMPING_RATIO,
+            r: Vec2::zeros(),
+            k: [0.0; 3],
+            separation: Vec2::zeros(),
+            softness: Softness::rigid(1.0),
+            max_impulse: 0.0,
+            impulse: Vec2::zeros(),
+        }
+    }
+
+    pub fn set_target(&mut self, target: Vec2) {
+        self.target = target;
+    }
+}
+
+impl Joint for MouseJoint {
+    fn get_entities(&self) -> Vec<EntityHandle> {
+        vec![self.entity]
+    }
+
+    fn prepare(&mut self, entities: &EntityMap, delta: f32) {
+        self.k = [0.0; 3];
+        self.impulse = Vec2::zeros();
+
+        let Some(e) = entities.get(&self.entity) else {
+            return;
+        };
+        let e = e.borrow();
+
+        self.r = self.local_anchor.rotate(e.get_angle());
+        self.k = point_mass_matrix(0.0, e.get_inverse_mass(), 0.0, e.get_inverse_inertia(), Vec2::zeros(), self.r);
+        self.separation = e.get_center() + self.r - self.target;
+        self.softness = Softness::new(self.frequency, self.damping_ratio, delta);
+        self.max_impulse = self.max_force * delta;
+    }
+
+    fn solve_velocity(&mut self, entities: &EntityMap) {
+        let Some(e) = entities.get(&self.entity) else {
+            return;
+        };
+        let mut e = e.borrow_mut();
+        let soft = self.softness;
+
+        let c_dot = e.get_velocity() + self.r.perp() * e.get_angular_velocity();
+        let impulse = solve_2x2(self.k, -(c_dot + self.separation * soft.bias_rate)) * soft.mass_scale
+            - self.impulse * soft.impulse_scale;
+
+        // Clamp the accumulated impulse so the drag force stays bounded
+        let old_impulse = self.impulse;
+        self.impulse += impulse;
+        let length = self.impulse.length();
+        if length > self.max_impulse {
+            self.impulse = self.impulse * (self.max_impulse / length);
+        }
+        let impulse = self.impulse - old_impulse;
+
+        let (m, i) = (e.get_inverse_mass(), e.get_inverse_inertia());
+        *e.get_velocity_mut() += impulse * m;
+        *e.get_angular_velocity_mut() += i * self.r.cross(&impulse);
+    }
+
+    fn render(&self, entities: &EntityMap) {
+        let Some(e) = entities.get(&self.entity) else {
+            return;
+        };
+        let e = e.borrow();
+        let anchor = e.get_center() + self.local_anchor.rotate(e.get_angle());
+
+        draw_line(anchor.x, anchor.y, self.target.x, self.target.y, 1.0, LIGHTGRAY);
+        draw_circle(anchor.x, anchor.y, 3.0, LIGHTGRAY);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Joint> {
+        Box::new(self.clone())
+    }
+}
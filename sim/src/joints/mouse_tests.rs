@@ -0,0 +1,50 @@
+use super::mouse::MouseJoint;
+use crate::entity::circle_entity::CircleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_query_point_finds_entity() {
+    let mut world = World::new(1000.0, 1000.0);
+    let handle = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+
+    assert_eq!(world.query_point(Vec2::new(105.0, 100.0)), Some(handle));
+    assert_eq!(world.query_point(Vec2::new(200.0, 100.0)), None);
+}
+
+#[test]
+fn test_mouse_joint_drags_entity_to_target() {
+    let mut world = World::new(1000.0, 1000.0);
+    let handle = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    let joint = world.add_joint(Box::new(MouseJoint::new(&world, handle, Vec2::new(100.0, 100.0), 1e4)));
+
+    world.get_joint_mut(joint).unwrap().as_any_mut().downcast_mut::<MouseJoint>().unwrap()
+        .set_target(Vec2::new(200.0, 150.0));
+
+    for _ in 0..180 {
+        world.step(1.0 / 60.0);
+    }
+
+    let position = world.get_entity(handle).unwrap().borrow().get_position();
+    assert!(position.dist(&Vec2::new(200.0, 150.0)) < 1.0);
+
+    world.remove_joint(joint);
+    assert!(world.joints.is_empty());
+}
+
+#[test]
+fn test_mouse_joint_force_is_bounded() {
+    let mut world = World::new(1000.0, 1000.0);
+    let handle = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    let mut joint = MouseJoint::new(&world, handle, Vec2::new(100.0, 100.0), 20.0);
+    joint.set_target(Vec2::new(900.0, 100.0));
+    world.add_joint(Box::new(joint));
+
+    world.step(1.0 / 60.0);
+
+    // A force of 20 on a mass of 2 can add at most 10/60 to the speed in one step
+    let velocity = world.get_entity(handle).unwrap().borrow().get_velocity();
+    assert!(velocity.length() <= 10.0 / 60.0 + 1e-4);
+}
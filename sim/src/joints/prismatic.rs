@@ -0,0 +1,251 @@
+use std::any::Any;
+use crate::entity::Entity;
+use crate::joints::{get_pair, solve_2x2, Joint, Motor, BAUMGARTE};
+use crate::world::{EntityHandle, EntityMap, World};
+use common::math::vectors::Vec2;
+use macroquad::color::ORANGE;
+use macroquad::shapes::{draw_circle_lines, draw_line};
+
+/// Lets `entity_b` slide along an axis fixed in `entity_a`, without relative rotation.
+#[derive(Clone)]
+pub struct PrismaticJoint {
+    pub entity_a: EntityHandle,
+    pub entity_b: EntityHandle,
+    pub local_anchor_a: Vec2,
+    pub local_anchor_b: Vec2,
+    /// Unit slide axis in `entity_a`'s frame.
+    pub local_axis: Vec2,
+    pub reference_angle: f32,
+    /// Lower and upper bounds for the translation along the axis.
+    pub limits: Option<(f32, f32)>,
+    pub motor: Option<Motor>,
+    axis: Vec2,
+    perp: Vec2,
+    a1: f32,
+    a2: f32,
+    s1: f32,
+    s2: f32,
+    axial_mass: f32,
+    k: [f32; 3],
+    bias: Vec2,
+    translation: f32,
+    inv_delta: f32,
+    delta: f32,
+    motor_impulse: f32,
+    lower_impulse: f32,
+    upper_impulse: f32,
+}
+
+impl PrismaticJoint {
+    /// Creates a joint at the world space `anchor` sliding along the world space `axis`.
+    pub fn new(world: &World, entity_a: EntityHandle, entity_b: EntityHandle, anchor: Vec2, axis: Vec2) -> Self {
+        debug_assert_ne!(entity_a, entity_b, "a prismatic joint needs two distinct entities");
+
+        let (mut local_anchor_a, mut local_anchor_b, mut local_axis, mut reference_angle) =
+            (Vec2::zeros(), Vec2::zeros(), axis.unit(), 0.0);
+
+        if let Some((a, b)) = get_pair(&world.entities, entity_a, entity_b) {
+            let (a, b) = (a.borrow(), b.borrow());
+            local_anchor_a = (anchor - a.get_center()).rotate(-a.get_angle());
+            local_anchor_b = (anchor - b.get_center()).rotate(-b.get_angle());
+            local_axis = local_axis.rotate(-a.get_angle());
+            reference_angle = b.get_angle() - a.get_angle();
+        }
+
+        Self {
+            entity_a,
+            entity_b,
+            local_anchor_a,
+            local_anchor_b,
+            local_axis,
+            reference_angle,
+            limits: None,
+            motor: None,
+            axis: Vec2::zeros(),
+            perp: Vec2::zeros(),
+            a1: 0.0,
+            a2: 0.0,
+            s1: 0.0,
+            s2: 0.0,
+            axial_mass: 0.0,
+            k: [0.0; 3],
+            bias: Vec2::zeros(),
+            translation: 0.0,
+            inv_delta: 0.0,
+            delta: 0.0,
+            motor_impulse: 0.0,
+            lower_impulse: 0.0,
+            upper_impulse: 0.0,
+        }
+    }
+
+    pub fn with_limits(mut self, lower: f32, upper: f32) -> Self {
+        self.limits = Some((lower.min(upper), lower.max(upper)));
+        self
+    }
+
+    pub fn with_motor(mut self, speed: f32, max_force: f32) -> Self {
+        self.motor = Some(Motor { speed, max_force });
+        self
+    }
+
+    /// Applies an impulse along the slide axis.
+    fn apply_axial(&self, a: &mut dyn Entity, b: &mut dyn Entity, impulse: f32) {
+        let p = self.axis * impulse;
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+
+        *a.get_velocity_mut() -= p * m_a;
+        *a.get_angular_velocity_mut() -= i_a * impulse * self.a1;
+        *b.get_velocity_mut() += p * m_b;
+        *b.get_angular_velocity_mut() += i_b * impulse * self.a2;
+    }
+
+    fn axial_speed(&self, a: &dyn Entity, b: &dyn Entity) -> f32 {
+        self.axis.dot(&(b.get_velocity() - a.get_velocity()))
+            + self.a2 * b.get_angular_velocity()
+            - self.a1 * a.get_angular_velocity()
+    }
+}
+
+impl Joint for PrismaticJoint {
+    fn get_entities(&self) -> Vec<EntityHandle> {
+        vec![self.entity_a, self.entity_b]
+    }
+
+    fn prepare(&mut self, entities: &EntityMap, delta: f32) {
+        self.axial_mass = 0.0;
+        self.k = [0.0; 3];
+        self.motor_impulse = 0.0;
+        self.lower_impulse = 0.0;
+        self.upper_impulse = 0.0;
+
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+
+        let r_a = self.local_anchor_a.rotate(a.get_angle());
+        let r_b = self.local_anchor_b.rotate(b.get_angle());
+        let d = (b.get_center() + r_b) - (a.get_center() + r_a);
+
+        self.axis = self.local_axis.rotate(a.get_angle());
+        self.perp = self.axis.perp();
+        self.translation = self.axis.dot(&d);
+
+        self.a1 = (d + r_a).cross(&self.axis);
+        self.a2 = r_b.cross(&self.axis);
+        self.s1 = (d + r_a).cross(&self.perp);
+        self.s2 = r_b.cross(&self.perp);
+
+        let axial = m_a + m_b + i_a * self.a1 * self.a1 + i_b * self.a2 * self.a2;
+        self.axial_mass = if axial > 0.0 { 1.0 / axial } else { 0.0 };
+
+        let angular = i_a + i_b;
+        self.k = [
+            m_a + m_b + i_a * self.s1 * self.s1 + i_b * self.s2 * self.s2,
+            i_a * self.s1 + i_b * self.s2,
+            if angular > 0.0 { angular } else { 1.0 },
+        ];
+
+        self.delta = delta;
+        self.inv_delta = 1.0 / delta;
+
+        let angle = b.get_angle() - a.get_angle() - self.reference_angle;
+        self.bias = Vec2::new(self.perp.dot(&d), angle) * (BAUMGARTE * self.inv_delta);
+    }
+
+    fn solve_velocity(&mut self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (mut a, mut b) = (a.borrow_mut(), b.borrow_mut());
+        let (a, b) = (&mut *a, &mut *b);
+
+        if self.axial_mass > 0.0 {
+            if let Some(motor) = &self.motor {
+                let c_dot = self.axial_speed(a, b);
+                let max_impulse = motor.max_force * self.delta;
+
+                let old_impulse = self.motor_impulse;
+                self.motor_impulse = (old_impulse + self.axial_mass * (motor.speed - c_dot)).clamp(-max_impulse, max_impulse);
+                self.apply_axial(a, b, self.motor_impulse - old_impulse);
+            }
+
+            if let Some((lower, upper)) = self.limits {
+                // Lower limit, speculative while the joint is still above it
+                let c = self.translation - lower;
+                let bias = if c > 0.0 { c * self.inv_delta } else { BAUMGARTE * c * self.inv_delta };
+                let c_dot = self.axial_speed(a, b);
+
+                let old_impulse = self.lower_impulse;
+                self.lower_impulse = (old_impulse - self.axial_mass * (c_dot + bias)).max(0.0);
+                self.apply_axial(a, b, self.lower_impulse - old_impulse);
+
+                // Upper limit, mirrored
+                let c = upper - self.translation;
+                let bias = if c > 0.0 { c * self.inv_delta } else { BAUMGARTE * c * self.inv_delta };
+                let c_dot = -self.axial_speed(a, b);
+
+                let old_impulse = self.upper_impulse;
+                self.upper_impulse = (old_impulse - self.axial_mass * (c_dot + bias)).max(0.0);
+                self.apply_axial(a, b, old_impulse - self.upper_impulse);
+            }
+        }
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+
+        let c_dot = Vec2::new(
+            self.perp.dot(&(b.get_velocity() - a.get_velocity()))
+                + self.s2 * b.get_angular_velocity()
+                - self.s1 * a.get_angular_velocity(),
+            b.get_angular_velocity() - a.get_angular_velocity(),
+        );
+        let impulse = solve_2x2(self.k, -(c_dot + self.bias));
+
+        let p = self.perp * impulse.x;
+        let l_a = impulse.x * self.s1 + impulse.y;
+        let l_b = impulse.x * self.s2 + impulse.y;
+
+        *a.get_velocity_mut() -= p * m_a;
+        *a.get_angular_velocity_mut() -= i_a * l_a;
+        *b.get_velocity_mut() += p * m_b;
+        *b.get_angular_velocity_mut() += i_b * l_b;
+    }
+
+    fn render(&self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let anchor_a = a.get_center() + self.local_anchor_a.rotate(a.get_angle());
+        let anchor_b = b.get_center() + self.local_anchor_b.rotate(b.get_angle());
+        let axis = self.local_axis.rotate(a.get_angle());
+
+        let (start, end) = match self.limits {
+            Some((lower, upper)) => (anchor_a + axis * lower, anchor_a + axis * upper),
+            None => (anchor_a, anchor_b),
+        };
+
+        draw_line(start.x, start.y, end.x, end.y, 1.5, ORANGE);
+        draw_circle_lines(anchor_b.x, anchor_b.y, 4.0, 1.5, ORANGE);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Joint> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,49 @@
+use super::prismatic::PrismaticJoint;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::{EntityHandle, World};
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DELTA: f32 = 1.0 / 60.0;
+
+fn slider_world() -> (World, EntityHandle, EntityHandle) {
+    let mut world = World::new(1000.0, 1000.0);
+    let ground = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 0.0, 10.0, 10.0))));
+    let slider = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(300.0, 300.0))));
+    (world, ground, slider)
+}
+
+#[test]
+fn test_prismatic_blocks_off_axis_motion() {
+    let (mut world, ground, slider) = slider_world();
+    world.add_joint(Box::new(PrismaticJoint::new(&world, ground, slider, Vec2::new(300.0, 300.0), Vec2::new(1.0, 0.0))));
+
+    world.get_entity(slider).unwrap().borrow_mut().get_velocity_mut().x = 30.0;
+    world.get_entity(slider).unwrap().borrow_mut().get_velocity_mut().y = 30.0;
+
+    for _ in 0..60 {
+        world.step(DELTA);
+    }
+
+    let slider = world.get_entity(slider).unwrap().borrow();
+    assert!(slider.get_position().x > 310.0);
+    assert!((slider.get_position().y - 300.0).abs() < 0.5);
+}
+
+#[test]
+fn test_prismatic_motor_stops_at_limit() {
+    let (mut world, ground, slider) = slider_world();
+    let joint = PrismaticJoint::new(&world, ground, slider, Vec2::new(300.0, 300.0), Vec2::new(1.0, 0.0))
+        .with_limits(-20.0, 20.0)
+        .with_motor(50.0, 1e4);
+    world.add_joint(Box::new(joint));
+
+    for _ in 0..120 {
+        world.step(DELTA);
+    }
+
+    let slider = world.get_entity(slider).unwrap().borrow();
+    assert!((slider.get_position().x - 320.0).abs() < 0.5);
+}
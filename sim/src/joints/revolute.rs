@@ -0,0 +1,198 @@
+use std::any::Any;
+use crate::joints::{get_pair, point_mass_matrix, solve_2x2, Joint, Motor, BAUMGARTE};
+use crate::world::{EntityHandle, EntityMap, World};
+use common::math::vectors::Vec2;
+use macroquad::color::SKYBLUE;
+use macroquad::shapes::{draw_circle_lines, draw_line};
+
+/// Pins two entities together at a shared anchor while letting them rotate freely around it.
+#[derive(Clone)]
+pub struct RevoluteJoint {
+    pub entity_a: EntityHandle,
+    pub entity_b: EntityHandle,
+    pub local_anchor_a: Vec2,
+    pub local_anchor_b: Vec2,
+    pub reference_angle: f32,
+    /// Lower and upper bounds for the relative angle, in radians.
+    pub limits: Option<(f32, f32)>,
+    pub motor: Option<Motor>,
+    r_a: Vec2,
+    r_b: Vec2,
+    k: [f32; 3],
+    axial_mass: f32,
+    point_bias: Vec2,
+    angle: f32,
+    inv_delta: f32,
+    delta: f32,
+    motor_impulse: f32,
+    lower_impulse: f32,
+    upper_impulse: f32,
+}
+
+impl RevoluteJoint {
+    /// Creates a joint at the world space `anchor`, taking the current pose as the zero angle.
+    pub fn new(world: &World, entity_a: EntityHandle, entity_b: EntityHandle, anchor: Vec2) -> Self {
+        debug_assert_ne!(entity_a, entity_b, "a revolute joint needs two distinct entities");
+
+        let (mut local_anchor_a, mut local_anchor_b, mut reference_angle) = (Vec2::zeros(), Vec2::zeros(), 0.0);
+
+        if let Some((a, b)) = get_pair(&world.entities, entity_a, entity_b) {
+            let (a, b) = (a.borrow(), b.borrow());
+            local_anchor_a = (anchor - a.get_center()).rotate(-a.get_angle());
+            local_anchor_b = (anchor - b.get_center()).rotate(-b.get_angle());
+            reference_angle = b.get_angle() - a.get_angle();
+        }
+
+        Self {
+            entity_a,
+            entity_b,
+            local_anchor_a,
+            local_anchor_b,
+            reference_angle,
+            limits: None,
+            motor: None,
+            r_a: Vec2::zeros(),
+            r_b: Vec2::zeros(),
+            k: [0.0; 3],
+            axial_mass: 0.0,
+            point_bias: Vec2::zeros(),
+            angle: 0.0,
+            inv_delta: 0.0,
+            delta: 0.0,
+            motor_impulse: 0.0,
+            lower_impulse: 0.0,
+            upper_impulse: 0.0,
+        }
+    }
+
+    pub fn with_limits(mut self, lower: f32, upper: f32) -> Self {
+        self.limits = Some((lower.min(upper), lower.max(upper)));
+        self
+    }
+
+    pub fn with_motor(mut self, speed: f32, max_torque: f32) -> Self {
+        self.motor = Some(Motor { speed, max_force: max_torque });
+        self
+    }
+}
+
+impl Joint for RevoluteJoint {
+    fn get_entities(&self) -> Vec<EntityHandle> {
+        vec![self.entity_a, self.entity_b]
+    }
+
+    fn prepare(&mut self, entities: &EntityMap, delta: f32) {
+        self.axial_mass = 0.0;
+        self.k = [0.0; 3];
+        self.motor_impulse = 0.0;
+        self.lower_impulse = 0.0;
+        self.upper_impulse = 0.0;
+
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+
+        self.r_a = self.local_anchor_a.rotate(a.get_angle());
+        self.r_b = self.local_anchor_b.rotate(b.get_angle());
+        self.k = point_mass_matrix(m_a, m_b, i_a, i_b, self.r_a, self.r_b);
+
+        let axial = i_a + i_b;
+        self.axial_mass = if axial > 0.0 { 1.0 / axial } else { 0.0 };
+
+        self.delta = delta;
+        self.inv_delta = 1.0 / delta;
+        self.angle = b.get_angle() - a.get_angle() - self.reference_angle;
+
+        let separation = (b.get_center() + self.r_b) - (a.get_center() + self.r_a);
+        self.point_bias = separation * (BAUMGARTE * self.inv_delta);
+    }
+
+    fn solve_velocity(&mut self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (mut a, mut b) = (a.borrow_mut(), b.borrow_mut());
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+
+        if self.axial_mass > 0.0 {
+            if let Some(motor) = &self.motor {
+                let c_dot = b.get_angular_velocity() - a.get_angular_velocity() - motor.speed;
+                let max_impulse = motor.max_force * self.delta;
+
+                let old_impulse = self.motor_impulse;
+                self.motor_impulse = (old_impulse - self.axial_mass * c_dot).clamp(-max_impulse, max_impulse);
+                let impulse = self.motor_impulse - old_impulse;
+
+                *a.get_angular_velocity_mut() -= i_a * impulse;
+                *b.get_angular_velocity_mut() += i_b * impulse;
+            }
+
+            if let Some((lower, upper)) = self.limits {
+                // Lower limit, speculative while the joint is still above it
+                let c = self.angle - lower;
+                let bias = if c > 0.0 { c * self.inv_delta } else { BAUMGARTE * c * self.inv_delta };
+                let c_dot = b.get_angular_velocity() - a.get_angular_velocity();
+
+                let old_impulse = self.lower_impulse;
+                self.lower_impulse = (old_impulse - self.axial_mass * (c_dot + bias)).max(0.0);
+                let impulse = self.lower_impulse - old_impulse;
+
+                *a.get_angular_velocity_mut() -= i_a * impulse;
+                *b.get_angular_velocity_mut() += i_b * impulse;
+
+                // Upper limit, mirrored
+                let c = upper - self.angle;
+                let bias = if c > 0.0 { c * self.inv_delta } else { BAUMGARTE * c * self.inv_delta };
+                let c_dot = a.get_angular_velocity() - b.get_angular_velocity();
+
+                let old_impulse = self.upper_impulse;
+                self.upper_impulse = (old_impulse - self.axial_mass * (c_dot + bias)).max(0.0);
+                let impulse = self.upper_impulse - old_impulse;
+
+                *a.get_angular_velocity_mut() += i_a * impulse;
+                *b.get_angular_velocity_mut() -= i_b * impulse;
+            }
+        }
+
+        let v_a = a.get_velocity() + self.r_a.perp() * a.get_angular_velocity();
+        let v_b = b.get_velocity() + self.r_b.perp() * b.get_angular_velocity();
+        let impulse = solve_2x2(self.k, -(v_b - v_a + self.point_bias));
+
+        *a.get_velocity_mut() -= impulse * m_a;
+        *a.get_angular_velocity_mut() -= i_a * self.r_a.cross(&impulse);
+        *b.get_velocity_mut() += impulse * m_b;
+        *b.get_angular_velocity_mut() += i_b * self.r_b.cross(&impulse);
+    }
+
+    fn render(&self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let (center_a, center_b) = (a.get_center(), b.get_center());
+        let anchor = center_a + self.local_anchor_a.rotate(a.get_angle());
+
+        draw_line(center_a.x, center_a.y, anchor.x, anchor.y, 1.5, SKYBLUE);
+        draw_line(center_b.x, center_b.y, anchor.x, anchor.y, 1.5, SKYBLUE);
+        draw_circle_lines(anchor.x, anchor.y, 4.0, 1.5, SKYBLUE);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Joint> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,63 @@
+use super::revolute::RevoluteJoint;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DELTA: f32 = 1.0 / 60.0;
+
+#[test]
+fn test_revolute_keeps_anchor_together() {
+    let mut world = World::new(1000.0, 1000.0);
+    let a = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    let b = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(140.0, 100.0))));
+    world.add_joint(Box::new(RevoluteJoint::new(&world, a, b, Vec2::new(120.0, 100.0))));
+
+    world.get_entity(b).unwrap().borrow_mut().get_velocity_mut().y = 50.0;
+
+    for _ in 0..120 {
+        world.step(DELTA);
+    }
+
+    let (a, b) = (world.get_entity(a).unwrap().borrow(), world.get_entity(b).unwrap().borrow());
+    let anchor_a = a.get_center() + Vec2::new(20.0, 0.0).rotate(a.get_angle());
+    let anchor_b = b.get_center() + Vec2::new(-20.0, 0.0).rotate(b.get_angle());
+
+    assert!(anchor_a.dist(&anchor_b) < 1.0);
+}
+
+#[test]
+fn test_revolute_motor_reaches_target_speed() {
+    let mut world = World::new(1000.0, 1000.0);
+    let ground = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 0.0, 10.0, 10.0))));
+    let wheel = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(200.0, 200.0))));
+    world.add_joint(Box::new(RevoluteJoint::new(&world, ground, wheel, Vec2::new(200.0, 200.0)).with_motor(3.0, 1e4)));
+
+    for _ in 0..60 {
+        world.step(DELTA);
+    }
+
+    let wheel = world.get_entity(wheel).unwrap().borrow();
+    assert!((wheel.get_angular_velocity() - 3.0).abs() < 0.05);
+    assert!(wheel.get_position().dist(&Vec2::new(200.0, 200.0)) < 0.5);
+}
+
+#[test]
+fn test_revolute_limits_stop_motor() {
+    let mut world = World::new(1000.0, 1000.0);
+    let ground = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 0.0, 10.0, 10.0))));
+    let flipper = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(200.0, 200.0))));
+    let joint = RevoluteJoint::new(&world, ground, flipper, Vec2::new(200.0, 200.0))
+        .with_limits(-0.5, 0.5)
+        .with_motor(5.0, 1e4);
+    world.add_joint(Box::new(joint));
+
+    for _ in 0..120 {
+        world.step(DELTA);
+    }
+
+    let angle = world.get_entity(flipper).unwrap().borrow().get_angle();
+    assert!((angle - 0.5).abs() < 0.05);
+}
@@ -0,0 +1,207 @@
+use std::any::Any;
+use std::f32::consts::PI;
+use crate::joints::{get_pair, Joint, Softness};
+use crate::world::{EntityHandle, EntityMap};
+use common::math::vectors::Vec2;
+use macroquad::color::YELLOW;
+use macroquad::shapes::draw_line;
+
+const COILS: usize = 8;
+const COIL_WIDTH: f32 = 6.0;
+
+#[derive(Clone)]
+pub enum SpringMode {
+    /// Hookean force `-k·x - c·v`, applied before integration.
+    Force { stiffness: f32, damping: f32 },
+    /// Soft constraint tuned by natural frequency (Hz) and damping ratio, solved on velocities.
+    Constraint { frequency: f32, damping_ratio: f32 },
+}
+
+#[derive(Clone)]
+pub struct SpringJoint {
+    pub entity_a: EntityHandle,
+    pub entity_b: EntityHandle,
+    pub rest_length: f32,
+    pub mode: SpringMode,
+    axis: Vec2,
+    mass: f32,
+    stretch: f32,
+    softness: Softness,
+    impulse: f32,
+}
+
+impl SpringJoint {
+    pub fn new(entity_a: EntityHandle, entity_b: EntityHandle, rest_length: f32, stiffness: f32, damping: f32) -> Self {
+        Self::with_mode(entity_a, entity_b, rest_length, SpringMode::Force { stiffness, damping })
+    }
+
+    pub fn new_stiff(entity_a: EntityHandle, entity_b: EntityHandle, rest_length: f32, frequency: f32, damping_ratio: f32) -> Self {
+        Self::with_mode(entity_a, entity_b, rest_length, SpringMode::Constraint { frequency, damping_ratio })
+    }
+
+    fn with_mode(entity_a: EntityHandle, entity_b: EntityHandle, rest_length: f32, mode: SpringMode) -> Self {
+        debug_assert_ne!(entity_a, entity_b, "a spring needs two distinct entities");
+
+        Self {
+            entity_a,
+            entity_b,
+            rest_length,
+            mode,
+            axis: Vec2::zeros(),
+            mass: 0.0,
+            stretch: 0.0,
+            softness: Softness::rigid(1.0),
+            impulse: 0.0,
+        }
+    }
+
+    /// Equivalent Hookean stiffness, used for energy reporting in both modes.
+    fn stiffness(&self, inv_mass_a: f32, inv_mass_b: f32) -> f32 {
+        match self.mode {
+            SpringMode::Force { stiffness, .. } => stiffness,
+            SpringMode::Constraint { frequency, .. } => {
+                let total_inv_mass = inv_mass_a + inv_mass_b;
+                if total_inv_mass == 0.0 {
+                    return 0.0;
+                }
+
+                let omega = 2.0 * PI * frequency;
+                omega * omega / total_inv_mass
+            }
+        }
+    }
+}
+
+impl Joint for SpringJoint {
+    fn get_entities(&self) -> Vec<EntityHandle> {
+        vec![self.entity_a, self.entity_b]
+    }
+
+    fn apply_forces(&mut self, entities: &EntityMap, _delta: f32) {
+        let SpringMode::Force { stiffness, damping } = self.mode else {
+            return;
+        };
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (mut a, mut b) = (a.borrow_mut(), b.borrow_mut());
+
+        let d = b.get_center() - a.get_center();
+        let length = d.length();
+        if length == 0.0 {
+            return;
+        }
+
+        let axis = d / length;
+        let stretch = length - self.rest_length;
+        let closing_speed = (b.get_velocity() - a.get_velocity()).dot(&axis);
+
+        let f = axis * (stiffness * stretch + damping * closing_speed);
+
+        a.apply_force(f);
+        b.apply_force(-f);
+    }
+
+    fn prepare(&mut self, entities: &EntityMap, delta: f32) {
+        self.mass = 0.0;
+        self.impulse = 0.0;
+
+        let SpringMode::Constraint { frequency, damping_ratio } = self.mode else {
+            return;
+        };
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let d = b.get_center() - a.get_center();
+        let length = d.length();
+        let total_inv_mass = a.get_inverse_mass() + b.get_inverse_mass();
+        if length == 0.0 || total_inv_mass == 0.0 {
+            return;
+        }
+
+        self.axis = d / length;
+        self.stretch = length - self.rest_length;
+        self.mass = 1.0 / total_inv_mass;
+        self.softness = Softness::new(frequency, damping_ratio, delta);
+    }
+
+    fn solve_velocity(&mut self, entities: &EntityMap) {
+        if self.mass == 0.0 {
+            return;
+        }
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (mut a, mut b) = (a.borrow_mut(), b.borrow_mut());
+
+        let soft = self.softness;
+        let c_dot = (b.get_velocity() - a.get_velocity()).dot(&self.axis);
+        let impulse = -self.mass * soft.mass_scale * (c_dot + soft.bias_rate * self.stretch)
+            - soft.impulse_scale * self.impulse;
+        self.impulse += impulse;
+
+        let p = self.axis * impulse;
+        let (inv_mass_a, inv_mass_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        *a.get_velocity_mut() -= p * inv_mass_a;
+        *b.get_velocity_mut() += p * inv_mass_b;
+    }
+
+    fn potential_energy(&self, entities: &EntityMap) -> f32 {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return 0.0;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let stretch = a.get_center().dist(&b.get_center()) - self.rest_length;
+        0.5 * self.stiffness(a.get_inverse_mass(), b.get_inverse_mass()) * stretch * stretch
+    }
+
+    fn render(&self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (start, end) = (a.borrow().get_center(), b.borrow().get_center());
+
+        let d = end - start;
+        let length = d.length();
+        if length == 0.0 {
+            return;
+        }
+
+        let axis = d / length;
+        let normal = Vec2::new(-axis.y, axis.x);
+        let lead = length * 0.1;
+        let coil_start = start + axis * lead;
+        let coil_length = length - 2.0 * lead;
+
+        let mut prev = start;
+        let mut next = coil_start;
+        draw_line(prev.x, prev.y, next.x, next.y, 1.5, YELLOW);
+
+        for i in 1..2 * COILS {
+            prev = next;
+            let side = if i % 2 == 1 { COIL_WIDTH } else { -COIL_WIDTH };
+            next = coil_start + axis * (coil_length * i as f32 / (2 * COILS) as f32) + normal * side;
+            draw_line(prev.x, prev.y, next.x, next.y, 1.5, YELLOW);
+        }
+
+        prev = next;
+        next = end - axis * lead;
+        draw_line(prev.x, prev.y, next.x, next.y, 1.5, YELLOW);
+        draw_line(next.x, next.y, end.x, end.y, 1.5, YELLOW);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Joint> {
+        Box::new(self.clone())
+    }
+}
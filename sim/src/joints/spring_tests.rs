@@ -0,0 +1,75 @@
+use super::spring::SpringJoint;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::world::{EntityHandle, World};
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn spawn_pair(world: &mut World, distance: f32) -> (EntityHandle, EntityHandle) {
+    let a = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    let b = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0 + distance, 100.0))));
+    (a, b)
+}
+
+fn distance(world: &World, a: EntityHandle, b: EntityHandle) -> f32 {
+    let a = world.get_entity(a).unwrap().borrow().get_center();
+    let b = world.get_entity(b).unwrap().borrow().get_center();
+    a.dist(&b)
+}
+
+#[test]
+fn test_soft_spring_pulls_towards_rest_length() {
+    let mut world = World::new(1000.0, 1000.0);
+    let (a, b) = spawn_pair(&mut world, 150.0);
+    world.add_joint(Box::new(SpringJoint::new(a, b, 50.0, 20.0, 5.0)));
+
+    for _ in 0..2000 {
+        world.step(1.0 / 60.0);
+    }
+
+    assert!((distance(&world, a, b) - 50.0).abs() < 1.0);
+}
+
+#[test]
+fn test_stiff_spring_pulls_towards_rest_length() {
+    let mut world = World::new(1000.0, 1000.0);
+    let (a, b) = spawn_pair(&mut world, 150.0);
+    world.add_joint(Box::new(SpringJoint::new_stiff(a, b, 50.0, 2.0, 1.0)));
+
+    for _ in 0..600 {
+        world.step(1.0 / 60.0);
+    }
+
+    assert!((distance(&world, a, b) - 50.0).abs() < 1.0);
+}
+
+#[test]
+fn test_spring_energy_is_zero_at_rest() {
+    let mut world = World::new(1000.0, 1000.0);
+    let (a, b) = spawn_pair(&mut world, 50.0);
+    world.add_joint(Box::new(SpringJoint::new(a, b, 50.0, 20.0, 1.0)));
+
+    assert_eq!(world.joint_energy(), 0.0);
+
+    let (c, d) = spawn_pair(&mut world, 60.0);
+    world.add_joint(Box::new(SpringJoint::new(c, d, 50.0, 20.0, 1.0)));
+
+    assert!((world.joint_energy() - 0.5 * 20.0 * 100.0).abs() < 1e-3);
+}
+
+#[test]
+fn test_spring_does_not_move_a_static_anchor() {
+    let mut world = World::new(1000.0, 1000.0);
+    let anchor = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(100.0, 100.0, 20.0, 20.0))));
+    let ball = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(300.0, 100.0))));
+    world.add_joint(Box::new(SpringJoint::new(anchor, ball, 50.0, 20.0, 5.0)));
+
+    for _ in 0..60 {
+        world.step(1.0 / 60.0);
+    }
+
+    let anchor = world.get_entity(anchor).unwrap().borrow();
+    assert_eq!(anchor.get_position(), Vec2::new(100.0, 100.0));
+    assert_eq!(anchor.get_velocity(), Vec2::zeros());
+}
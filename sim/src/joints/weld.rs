@@ -0,0 +1,159 @@
+use std::any::Any;
+use crate::joints::{get_pair, point_mass_matrix, solve_2x2, Joint, Softness};
+use crate::world::{EntityHandle, EntityMap, World};
+use common::math::vectors::Vec2;
+use macroquad::color::MAGENTA;
+use macroquad::shapes::{draw_line, draw_rectangle_lines};
+
+/// Glues two entities together at an anchor, optionally with some springiness.
+#[derive(Clone)]
+pub struct WeldJoint {
+    pub entity_a: EntityHandle,
+    pub entity_b: EntityHandle,
+    pub local_anchor_a: Vec2,
+    pub local_anchor_b: Vec2,
+    pub reference_angle: f32,
+    /// Natural frequency (Hz) of the weld, zero for a rigid one.
+    pub frequency: f32,
+    pub damping_ratio: f32,
+    r_a: Vec2,
+    r_b: Vec2,
+    k: [f32; 3],
+    axial_mass: f32,
+    separation: Vec2,
+    angle: f32,
+    softness: Softness,
+    linear_impulse: Vec2,
+    angular_impulse: f32,
+}
+
+impl WeldJoint {
+    /// Creates a rigid weld at the world space `anchor`, keeping the current relative pose.
+    pub fn new(world: &World, entity_a: EntityHandle, entity_b: EntityHandle, anchor: Vec2) -> Self {
+        debug_assert_ne!(entity_a, entity_b, "a weld joint needs two distinct entities");
+
+        let (mut local_anchor_a, mut local_anchor_b, mut reference_angle) = (Vec2::zeros(), Vec2::zeros(), 0.0);
+
+        if let Some((a, b)) = get_pair(&world.entities, entity_a, entity_b) {
+            let (a, b) = (a.borrow(), b.borrow());
+            local_anchor_a = (anchor - a.get_center()).rotate(-a.get_angle());
+            local_anchor_b = (anchor - b.get_center()).rotate(-b.get_angle());
+            reference_angle = b.get_angle() - a.get_angle();
+        }
+
+        Self {
+            entity_a,
+            entity_b,
+            local_anchor_a,
+            local_anchor_b,
+            reference_angle,
+            frequency: 0.0,
+            damping_ratio: 0.0,
+            r_a: Vec2::zeros(),
+            r_b: Vec2::zeros(),
+            k: [0.0; 3],
+            axial_mass: 0.0,
+            separation: Vec2::zeros(),
+            angle: 0.0,
+            softness: Softness::rigid(1.0),
+            linear_impulse: Vec2::zeros(),
+            angular_impulse: 0.0,
+        }
+    }
+
+    pub fn with_softness(mut self, frequency: f32, damping_ratio: f32) -> Self {
+        self.frequency = frequency;
+        self.damping_ratio = damping_ratio;
+        self
+    }
+}
+
+impl Joint for WeldJoint {
+    fn get_entities(&self) -> Vec<EntityHandle> {
+        vec![self.entity_a, self.entity_b]
+    }
+
+    fn prepare(&mut self, entities: &EntityMap, delta: f32) {
+        self.axial_mass = 0.0;
+        self.k = [0.0; 3];
+        self.linear_impulse = Vec2::zeros();
+        self.angular_impulse = 0.0;
+
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+
+        self.r_a = self.local_anchor_a.rotate(a.get_angle());
+        self.r_b = self.local_anchor_b.rotate(b.get_angle());
+        self.k = point_mass_matrix(m_a, m_b, i_a, i_b, self.r_a, self.r_b);
+
+        let axial = i_a + i_b;
+        self.axial_mass = if axial > 0.0 { 1.0 / axial } else { 0.0 };
+
+        self.separation = (b.get_center() + self.r_b) - (a.get_center() + self.r_a);
+        self.angle = b.get_angle() - a.get_angle() - self.reference_angle;
+        self.softness = Softness::new(self.frequency, self.damping_ratio, delta);
+    }
+
+    fn solve_velocity(&mut self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (mut a, mut b) = (a.borrow_mut(), b.borrow_mut());
+
+        let (m_a, m_b) = (a.get_inverse_mass(), b.get_inverse_mass());
+        let (i_a, i_b) = (a.get_inverse_inertia(), b.get_inverse_inertia());
+        let soft = self.softness;
+
+        if self.axial_mass > 0.0 {
+            let c_dot = b.get_angular_velocity() - a.get_angular_velocity();
+            let impulse = -self.axial_mass * soft.mass_scale * (c_dot + soft.bias_rate * self.angle)
+                - soft.impulse_scale * self.angular_impulse;
+            self.angular_impulse += impulse;
+
+            *a.get_angular_velocity_mut() -= i_a * impulse;
+            *b.get_angular_velocity_mut() += i_b * impulse;
+        }
+
+        let v_a = a.get_velocity() + self.r_a.perp() * a.get_angular_velocity();
+        let v_b = b.get_velocity() + self.r_b.perp() * b.get_angular_velocity();
+        let rhs = -(v_b - v_a + self.separation * soft.bias_rate);
+        let impulse = solve_2x2(self.k, rhs) * soft.mass_scale - self.linear_impulse * soft.impulse_scale;
+        self.linear_impulse += impulse;
+
+        *a.get_velocity_mut() -= impulse * m_a;
+        *a.get_angular_velocity_mut() -= i_a * self.r_a.cross(&impulse);
+        *b.get_velocity_mut() += impulse * m_b;
+        *b.get_angular_velocity_mut() += i_b * self.r_b.cross(&impulse);
+    }
+
+    fn render(&self, entities: &EntityMap) {
+        let Some((a, b)) = get_pair(entities, self.entity_a, self.entity_b) else {
+            return;
+        };
+        let (a, b) = (a.borrow(), b.borrow());
+
+        let (center_a, center_b) = (a.get_center(), b.get_center());
+        let anchor = center_a + self.local_anchor_a.rotate(a.get_angle());
+
+        draw_line(center_a.x, center_a.y, anchor.x, anchor.y, 1.5, MAGENTA);
+        draw_line(center_b.x, center_b.y, anchor.x, anchor.y, 1.5, MAGENTA);
+        draw_rectangle_lines(anchor.x - 4.0, anchor.y - 4.0, 8.0, 8.0, 1.5, MAGENTA);
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Joint> {
+        Box::new(self.clone())
+    }
+}
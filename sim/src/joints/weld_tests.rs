@@ -0,0 +1,24 @@
+use super::weld::WeldJoint;
+use crate::entity::circle_entity::CircleEntity;
+use crate::world::World;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_weld_keeps_relative_pose() {
+    let mut world = World::new(1000.0, 1000.0);
+    let a = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    let b = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(130.0, 100.0))));
+    world.add_joint(Box::new(WeldJoint::new(&world, a, b, Vec2::new(115.0, 100.0))));
+
+    world.get_entity(b).unwrap().borrow_mut().get_velocity_mut().y = 40.0;
+
+    for _ in 0..120 {
+        world.step(1.0 / 60.0);
+    }
+
+    let (a, b) = (world.get_entity(a).unwrap().borrow(), world.get_entity(b).unwrap().borrow());
+    assert!((a.get_center().dist(&b.get_center()) - 30.0).abs() < 0.5);
+    assert!((a.get_angle() - b.get_angle()).abs() < 0.01);
+}
@@ -0,0 +1,7 @@
+pub mod batch;
+pub mod collisions;
+pub mod effectors;
+pub mod entity;
+pub mod joints;
+pub mod playground;
+pub mod world;
@@ -1,47 +1,99 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use crate::effectors::forces::mouse_gravity::MouseGravity;
-use crate::effectors::Effector;
-use crate::entity::circle_entity::CircleEntity;
-use crate::entity::rectangle_entity::RectangleEntity;
-use crate::world::{EntityRef, World};
+use physics_playground::effectors::forces::wind_field::WindSource;
+use physics_playground::playground::energy_plot::EnergyPlot;
+use physics_playground::playground::replay::{play_headless, Recording, Replay};
+use physics_playground::playground::stats_overlay::draw_step_stats;
+use physics_playground::playground::{default_world, set_wind, AttractorAnchor, BlastKind, Command, Playground};
+use physics_playground::world::scene::Scene;
+use physics_playground::world::World;
 use common::math::vectors::Vec2;
 use macroquad::color::{BLACK, WHITE};
 use macroquad::input::{
-    is_mouse_button_pressed, mouse_position, MouseButton
-    ,
+    is_key_down, is_key_pressed, is_mouse_button_pressed, is_mouse_button_released, mouse_position, KeyCode,
+    MouseButton,
 };
 use macroquad::text::draw_text;
 use macroquad::time::{get_fps, get_frame_time};
 use macroquad::window::{clear_background, next_frame, screen_height, screen_width};
-use std::cell::RefCell;
-use std::rc::Rc;
+use macroquad::Window;
+use std::io;
 
-mod collisions;
-pub mod effectors;
-mod entity;
-mod world;
+const FIXED_DELTA: f32 = 1.0 / 60.0;
+const DEFAULT_SCENE_PATH: &str = "scene.ron";
+const PLOT_FRAMES: usize = 300;
 
-const BB_WIDTH: f32 = 15.0;
+fn main() {
+    // `--replay <file> --headless` plays a recording back without a window and checks where it ends up
+    if std::env::args().any(|arg| arg == "--headless") {
+        let Some(path) = arg_value("--replay") else {
+            eprintln!("--headless needs a recording to --replay");
+            std::process::exit(2);
+        };
+        std::process::exit(replay_headless(&path));
+    }
 
-#[macroquad::main("Physics Playground")]
-async fn main() {
-    let mut world = World::new(screen_width(), screen_height());
-    let mut mouse_grav = MouseGravity::new(0.1);
+    Window::new("Physics Playground", run());
+}
 
-    // Adding world bounding boxes
-    let bb1 = RectangleEntity::new_static(0.0, 0.0, BB_WIDTH, screen_height());
-    let bb2 = RectangleEntity::new_static(0.0, 0.0, screen_width(), BB_WIDTH);
-    let bb3 = RectangleEntity::new_static(0.0, screen_height() - BB_WIDTH, screen_width(), BB_WIDTH);
-    let bb4 = RectangleEntity::new_static(screen_width() - BB_WIDTH, 0.0, BB_WIDTH, screen_height());
+async fn run() {
+    let (width, height) = (screen_width(), screen_height());
 
-    world.add_entity(Rc::new(RefCell::new(bb1)));
-    world.add_entity(Rc::new(RefCell::new(bb2)));
-    world.add_entity(Rc::new(RefCell::new(bb3)));
-    world.add_entity(Rc::new(RefCell::new(bb4)));
+    // `--scene <file>` loads a saved world instead of the default room, F5 saves back to it
+    let scene_path = arg_value("--scene");
+    let mut world = match &scene_path {
+        Some(path) => World::load_scene(path).unwrap_or_else(|err| {
+            eprintln!("could not load scene {}: {}", path, err);
+            default_world(width, height)
+        }),
+        None => default_world(width, height),
+    };
+    let scene_path = scene_path.unwrap_or_else(|| DEFAULT_SCENE_PATH.to_string());
+
+    // `--wind <file>` loads a grid that is blowing from the start
+    if let Some(path) = arg_value("--wind") {
+        match WindSource::load_grid(&path) {
+            Ok(grid) => set_wind(&mut world, grid),
+            Err(err) => eprintln!("could not load wind grid {}: {}", path, err),
+        }
+    }
+
+    // `--record <file>` records every command, F8 writes the recording out. Recordings and
+    // `--deterministic` step at a fixed rate whatever the frame rate, and show the state hash to compare runs by
+    let record_path = arg_value("--record");
+    let deterministic = record_path.is_some() || std::env::args().any(|arg| arg == "--deterministic");
+    let mut recording = record_path.as_ref().map(|_| Recording::new(Scene::describe(&world), FIXED_DELTA, width, height));
+
+    // `--replay <file>` plays a recording back in the window instead of taking input
+    let mut replay: Option<Replay> = None;
+    let mut playground = match (arg_value("--replay"), &recording) {
+        (Some(path), _) => match start_replay(&path) {
+            Ok((playground, started)) => {
+                replay = Some(started);
+                playground
+            }
+            Err(err) => {
+                eprintln!("could not replay {}: {}", path, err);
+                Playground::new(world, width, height)
+            }
+        },
+        (None, Some(recording)) => recording.playground().unwrap_or_else(|err| {
+            eprintln!("could not start recording: {}", err);
+            std::process::exit(2);
+        }),
+        (None, None) => {
+            if deterministic {
+                world.set_fixed_timestep(Some(FIXED_DELTA));
+            }
+            Playground::new(world, width, height)
+        }
+    };
 
     let mut i = 0;
     let mut fps = get_fps();
+    let mut energy_plot: Option<EnergyPlot> = None;
+    let mut show_stats = false;
+    let mut input = InputState { pointer: None, size: Vec2::new(width, height), engines: [false; 3] };
 
     loop {
         clear_background(BLACK);
@@ -50,38 +102,211 @@ async fn main() {
 
         if i % 100 == 0 {
             fps = get_fps();
-            world.update_size(screen_width(), screen_height());
         }
 
-        if is_mouse_button_pressed(MouseButton::Left) {
-            let (x, y) = mouse_position();
-            let a = Vec2 { x, y };
-            let e = CircleEntity::new(a.x, a.y);
+        // F3 shows where the step's time goes, it stays available while replaying
+        if is_key_pressed(KeyCode::F3) {
+            show_stats = !show_stats;
+        }
 
-            let rc: EntityRef = Rc::new(RefCell::new(e));
+        if let Some(replay) = &mut replay {
+            if !replay.is_finished(&playground) {
+                replay.play_step(&mut playground);
 
-            world.add_entity(Rc::clone(&rc));
-            mouse_grav.add_entity(Rc::downgrade(&rc))
-        }
+                if replay.is_finished(&playground) {
+                    replay.apply_due(&mut playground);
+                    report_replay(replay, &playground);
+                }
+            }
+        } else {
+            for command in read_commands(&mut input) {
+                if let Some(recording) = &mut recording {
+                    recording.record(playground.steps, &command);
+                }
+                playground.apply(&command);
+            }
 
-        if is_mouse_button_pressed(MouseButton::Right) {
-            let (x, y) = mouse_position();
-            let a = Vec2 { x, y };
-            let e = RectangleEntity::new(a.x, a.y, 30.0, 30.0);
+            // D shows the energy plot, which starts over each time it is opened
+            if is_key_pressed(KeyCode::D) {
+                energy_plot = match energy_plot {
+                    Some(_) => None,
+                    None => Some(EnergyPlot::new(PLOT_FRAMES)),
+                };
+            }
 
+            if is_key_pressed(KeyCode::F5) {
+                match playground.world.save_scene(&scene_path) {
+                    Ok(()) => println!("saved scene to {}", scene_path),
+                    Err(err) => eprintln!("could not save scene {}: {}", scene_path, err),
+                }
+            }
 
-            let rc: EntityRef = Rc::new(RefCell::new(e));
+            if is_key_pressed(KeyCode::F8) && let (Some(recording), Some(path)) = (&mut recording, &record_path) {
+                recording.finish(&playground);
+                match recording.save(path) {
+                    Ok(()) => println!("saved {} steps of recording to {}", recording.steps, path),
+                    Err(err) => eprintln!("could not save recording {}: {}", path, err),
+                }
+            }
 
-            world.add_entity(Rc::clone(&rc));
-            mouse_grav.add_entity(Rc::downgrade(&rc))
+            playground.advance(get_frame_time());
         }
 
-        mouse_grav.update(get_frame_time());
-        world.render_entities();
+        playground.world.render_entities();
+
+        if let Some(plot) = &mut energy_plot {
+            plot.push(playground.world.diagnostics());
+            plot.draw(screen_width() - 420.0, 20.0, 400.0, 200.0);
+        }
+
+        if show_stats {
+            draw_step_stats(playground.world.stats(), 20.0, 240.0);
+        }
 
         draw_text(&format!("{} fps", fps), 20.0, 20.0, 30.0, WHITE);
+        draw_text(&format!("{:.1} spring energy", playground.world.joint_energy()), 20.0, 80.0, 30.0, WHITE);
+        draw_text(&format!("{} falloff", playground.falloff_name()), 20.0, 110.0, 30.0, WHITE);
+        draw_text(&format!("{:+} spawn charge", playground.spawn_charge()), 20.0, 140.0, 30.0, WHITE);
+        if deterministic || replay.is_some() {
+            draw_text(&format!("state {:016x}", playground.world.state_hash()), 20.0, 170.0, 30.0, WHITE);
+        }
+        if let Some(replay) = &replay {
+            draw_text(&format!("replay {}/{}", playground.steps, replay.recording.steps), 20.0, 200.0, 30.0, WHITE);
+        }
 
         i %= 100;
         next_frame().await;
     }
 }
+
+fn arg_value(name: &str) -> Option<String> {
+    std::env::args().skip_while(|arg| arg != name).nth(1)
+}
+
+fn start_replay(path: &str) -> io::Result<(Playground, Replay)> {
+    let recording = Recording::load(path)?;
+    Ok((recording.playground()?, Replay::new(recording)))
+}
+
+fn report_replay(replay: &Replay, playground: &Playground) {
+    println!("played {} steps, state {:016x}", playground.steps, playground.world.state_hash());
+
+    match replay.matches(playground) {
+        Some(true) => println!("the replay matches the recording"),
+        Some(false) => println!("the replay diverged from the recording"),
+        None => {}
+    }
+}
+
+/// Exit code for a headless replay: zero when it matches the recording, one when it diverged.
+fn replay_headless(path: &str) -> i32 {
+    match Recording::load(path).and_then(play_headless) {
+        Ok((playground, replay)) => {
+            report_replay(&replay, &playground);
+            if replay.matches(&playground) == Some(false) { 1 } else { 0 }
+        }
+        Err(err) => {
+            eprintln!("could not replay {}: {}", path, err);
+            2
+        }
+    }
+}
+
+/// Input seen on earlier frames, so continuous input only turns into commands when it changes.
+struct InputState {
+    pointer: Option<Vec2>,
+    size: Vec2,
+    engines: [bool; 3],
+}
+
+/// Turns this frame's mouse and keyboard input into commands.
+fn read_commands(input: &mut InputState) -> Vec<Command> {
+    let mut commands = vec![];
+    let (x, y) = mouse_position();
+    let cursor = Vec2::new(x, y);
+    let shift = is_key_down(KeyCode::LeftShift);
+    let control = is_key_down(KeyCode::LeftControl);
+    let alt = is_key_down(KeyCode::LeftAlt);
+
+    let size = Vec2::new(screen_width(), screen_height());
+    if size != input.size {
+        input.size = size;
+        commands.push(Command::Resize { width: size.x, height: size.y });
+    }
+
+    if input.pointer != Some(cursor) {
+        input.pointer = Some(cursor);
+        commands.push(Command::Pointer(cursor));
+    }
+
+    if is_mouse_button_pressed(MouseButton::Left) {
+        commands.push(Command::Press(cursor));
+    }
+
+    if is_mouse_button_released(MouseButton::Left) {
+        commands.push(Command::Release);
+    }
+
+    if is_mouse_button_pressed(MouseButton::Right) {
+        commands.push(Command::SpawnCrate(cursor));
+    }
+
+    let pressed = [
+        (KeyCode::S, Command::Spring { stiff: shift }),
+        (KeyCode::P, Command::Pin { at: cursor, motor: shift, limited: control }),
+        (KeyCode::L, Command::Slider { motor: shift, limited: control }),
+        (KeyCode::W, Command::Weld { at: cursor, soft: shift }),
+        (KeyCode::G, Command::ToggleGravity),
+        (KeyCode::N, Command::ToggleMutualGravity { balls_only: shift }),
+        (KeyCode::Q, Command::CycleCharge),
+        (KeyCode::E, Command::ToggleCoulomb),
+        (KeyCode::M, if shift { Command::ReverseMagneticField } else { Command::ToggleMagneticField }),
+        (KeyCode::V, Command::SwapWind),
+        (KeyCode::T, Command::Gust { at: cursor }),
+        (KeyCode::B, Command::CyclePool),
+        (KeyCode::F, Command::CycleFalloff),
+        (KeyCode::A, Command::Attractor {
+            at: cursor,
+            repel: shift,
+            anchor: if control {
+                AttractorAnchor::LastSpawned
+            } else if alt {
+                AttractorAnchor::Pointer
+            } else {
+                AttractorAnchor::Fixed
+            },
+        }),
+        (KeyCode::C, Command::ClearFields),
+        (KeyCode::Z, Command::Vortex { at: cursor, reverse: shift }),
+        (KeyCode::U, Command::Fan { at: cursor }),
+        (KeyCode::K, if shift { Command::ReverseConveyor } else { Command::ToggleConveyor }),
+        (KeyCode::O, Command::Ship { at: cursor }),
+        (KeyCode::X, Command::Explosion {
+            at: cursor,
+            kind: if control {
+                BlastKind::Quadratic
+            } else if alt {
+                BlastKind::Constant
+            } else {
+                BlastKind::Linear
+            },
+            occlusion: !shift,
+        }),
+        (KeyCode::F6, Command::Snapshot),
+        (KeyCode::F7, Command::Rewind),
+    ];
+
+    for (key, command) in pressed {
+        if is_key_pressed(key) {
+            commands.push(command);
+        }
+    }
+
+    let engines = [is_key_down(KeyCode::Up), is_key_down(KeyCode::Left), is_key_down(KeyCode::Right)];
+    if engines != input.engines {
+        input.engines = engines;
+        commands.push(Command::Engines { main: engines[0], left: engines[1], right: engines[2] });
+    }
+
+    commands
+}
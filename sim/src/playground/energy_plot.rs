@@ -0,0 +1,57 @@
+use crate::world::diagnostics::Diagnostics;
+use macroquad::color::{Color, GRAY, ORANGE, RED, SKYBLUE, WHITE};
+use macroquad::shapes::{draw_line, draw_rectangle_lines};
+use macroquad::text::draw_text;
+use std::collections::VecDeque;
+
+/// Name, colour and value of one plotted line.
+type Series = (&'static str, Color, fn(&Diagnostics) -> f32);
+
+const SERIES: [Series; 4] = [
+    ("kinetic", ORANGE, |d| d.kinetic_energy),
+    ("potential", SKYBLUE, |d| d.potential_energy + d.joint_energy),
+    ("total", WHITE, Diagnostics::total_energy),
+    ("collision loss", RED, |d| d.collision_loss),
+];
+
+/// Scrolling plot of the world's energies over the last `capacity` frames. A total that drifts
+/// with nothing dissipating points at the integrator, one that jumps at contacts at the collision response.
+pub struct EnergyPlot {
+    samples: VecDeque<Diagnostics>,
+    capacity: usize,
+}
+
+impl EnergyPlot {
+    pub fn new(capacity: usize) -> Self {
+        Self { samples: VecDeque::with_capacity(capacity), capacity }
+    }
+
+    pub fn push(&mut self, diagnostics: Diagnostics) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(diagnostics);
+    }
+
+    /// Draws the plot in the box at `x`, `y`, all series sharing one vertical scale.
+    pub fn draw(&self, x: f32, y: f32, width: f32, height: f32) {
+        let (min, max) = self.samples.iter()
+            .flat_map(|d| SERIES.iter().map(move |(_, _, value)| value(d)))
+            .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+        let range = (max - min).max(1.0);
+        let to_y = |v: f32| y + height - (v - min) / range * height;
+        let step = width / (self.capacity.max(2) - 1) as f32;
+
+        draw_rectangle_lines(x, y, width, height, 1.0, GRAY);
+
+        for (i, (name, color, value)) in SERIES.iter().enumerate() {
+            for (j, (a, b)) in self.samples.iter().zip(self.samples.iter().skip(1)).enumerate() {
+                let x0 = x + j as f32 * step;
+                draw_line(x0, to_y(value(a)), x0 + step, to_y(value(b)), 1.0, *color);
+            }
+
+            let latest = self.samples.back().map(value).unwrap_or(0.0);
+            draw_text(&format!("{} {:.0}", name, latest), x + 5.0, y + 15.0 + 15.0 * i as f32, 16.0, *color);
+        }
+    }
+}
@@ -0,0 +1,601 @@
+pub mod energy_plot;
+pub mod replay;
+pub mod stats_overlay;
+#[cfg(test)]
+mod replay_tests;
+
+use crate::effectors::forces::conveyor::Conveyor;
+use crate::effectors::forces::coulomb::Coulomb;
+use crate::effectors::forces::drag::{Drag, DragCoefficients};
+use crate::effectors::forces::explosion::{BlastFalloff, Explosion};
+use crate::effectors::forces::fluid_volume::{FluidRegion, FluidVolume};
+use crate::effectors::forces::magnetic_field::UniformMagneticField;
+use crate::effectors::forces::mouse_gravity::MouseGravity;
+use crate::effectors::forces::mutual_gravity::MutualGravity;
+use crate::effectors::forces::point_attractor::{AttractorSource, Falloff, PointAttractor};
+use crate::effectors::forces::push_zone::PushZone;
+use crate::effectors::forces::thruster::Thruster;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::effectors::forces::vortex::Vortex;
+use crate::effectors::forces::wind_field::{WindField, WindSource};
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::entity::EntityType;
+use crate::joints::mouse::MouseJoint;
+use crate::joints::prismatic::PrismaticJoint;
+use crate::joints::revolute::RevoluteJoint;
+use crate::joints::spring::SpringJoint;
+use crate::joints::weld::WeldJoint;
+use crate::world::snapshot::Snapshot;
+use crate::world::{EffectorHandle, EntityHandle, JointHandle, World};
+use common::math::vectors::Vec2;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::f32::consts::FRAC_PI_4;
+use std::rc::Rc;
+
+const BB_WIDTH: f32 = 15.0;
+const SPRING_REST_LENGTH: f32 = 60.0;
+const MOTOR_SPEED: f32 = 4.0;
+const MOTOR_MAX_TORQUE: f32 = 5000.0;
+const SLIDER_SPEED: f32 = 40.0;
+const SLIDER_MAX_FORCE: f32 = 500.0;
+const SLIDER_TRAVEL: f32 = 50.0;
+const WELD_FREQUENCY: f32 = 3.0;
+const WELD_DAMPING_RATIO: f32 = 0.5;
+const DRAG_MAX_FORCE: f32 = 1000.0;
+const GRAVITY: Vec2 = Vec2 { x: 0.0, y: 200.0 };
+const MUTUAL_G: f32 = 2.0e5;
+const MUTUAL_SOFTENING: f32 = 10.0;
+const ATTRACTOR_MIN_RADIUS: f32 = 20.0;
+const ATTRACTOR_MAX_RADIUS: f32 = 400.0;
+const AIR_DRAG: DragCoefficients = DragCoefficients { linear: 0.0005, quadratic: 0.0 };
+const ANGULAR_DRAG: f32 = 0.01;
+const WATER_DENSITY: f32 = 0.005;
+const WATER_DRAG: f32 = 0.005;
+const EXPLOSION_STRENGTH: f32 = 1000.0;
+const EXPLOSION_RADIUS: f32 = 200.0;
+const EXPLOSION_LIFETIME: f32 = 0.4;
+const EXPLOSION_PRIORITY: i32 = -10;
+const GUST_SPEED: f32 = 600.0;
+const GUST_DURATION: f32 = 1.0;
+const BALL_GROUP: u32 = 1 << 1;
+const CRATE_GROUP: u32 = 1 << 2;
+/// Tag given to everything spawned with the mouse, which the mouse gravity acts on.
+const SPAWN_TAG: &str = "spawned";
+const COULOMB_K: f32 = 2.0e5;
+const COULOMB_SOFTENING: f32 = 10.0;
+const MAGNETIC_FIELD: f32 = 2.0;
+const SPAWN_CHARGES: [f32; 3] = [0.0, 1.0, -1.0];
+const VORTEX_STRENGTH: f32 = 400.0;
+const VORTEX_PULL: f32 = 100.0;
+const VORTEX_RADIUS: f32 = 150.0;
+const FAN_SIZE: Vec2 = Vec2 { x: 120.0, y: 300.0 };
+const FAN_ACCELERATION: Vec2 = Vec2 { x: 0.0, y: -400.0 };
+const CONVEYOR_SPEED: f32 = 100.0;
+const SHIP_SIZE: Vec2 = Vec2 { x: 20.0, y: 40.0 };
+const SHIP_MAX_SPEED: f32 = 300.0;
+const SHIP_MAX_FORCE: f32 = 1500.0;
+const MAIN_ENGINE_THRUST: f32 = 1000.0;
+const STEERING_THRUST: f32 = 100.0;
+const FALLOFF_NAMES: [&str; 4] = ["inverse square", "linear spring", "constant", "linear fade"];
+
+/// What a new point attractor is anchored to.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AttractorAnchor {
+    Fixed,
+    /// The most recently spawned entity.
+    LastSpawned,
+    Pointer,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum BlastKind {
+    Linear,
+    Quadratic,
+    Constant,
+}
+
+/// Something the front-end does to the world. Commands carry every position and size they depend on,
+/// so applying the same commands before the same steps reproduces a session exactly.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Command {
+    Resize { width: f32, height: f32 },
+    /// The mouse moved, steering mouse gravity, pointer attractors and the dragged entity.
+    Pointer(Vec2),
+    /// Grabs the dynamic entity under the point, or spawns a ball there.
+    Press(Vec2),
+    Release,
+    SpawnCrate(Vec2),
+    /// The joint commands connect the two most recently spawned entities.
+    Spring { stiff: bool },
+    Pin { at: Vec2, motor: bool, limited: bool },
+    Slider { motor: bool, limited: bool },
+    Weld { at: Vec2, soft: bool },
+    ToggleGravity,
+    ToggleMutualGravity { balls_only: bool },
+    CycleCharge,
+    ToggleCoulomb,
+    ToggleMagneticField,
+    ReverseMagneticField,
+    SwapWind,
+    /// A gust blowing away from the side of the window `at` is on.
+    Gust { at: Vec2 },
+    CyclePool,
+    CycleFalloff,
+    Attractor { at: Vec2, repel: bool, anchor: AttractorAnchor },
+    ClearFields,
+    Vortex { at: Vec2, reverse: bool },
+    Fan { at: Vec2 },
+    ToggleConveyor,
+    ReverseConveyor,
+    Ship { at: Vec2 },
+    /// Which of the ships' engines are firing.
+    Engines { main: bool, left: bool, right: bool },
+    Explosion { at: Vec2, kind: BlastKind, occlusion: bool },
+    Snapshot,
+    Rewind,
+}
+
+#[derive(Copy, Clone)]
+enum Engine {
+    Main,
+    Left,
+    Right,
+}
+
+/// The world together with the tools the front-end drives it with.
+pub struct Playground {
+    pub world: World,
+    /// Window size, which the gust and pool commands measure against.
+    size: Vec2,
+    /// Steps taken since the playground was created.
+    pub steps: u64,
+    gravity: EffectorHandle,
+    mutual_gravity: EffectorHandle,
+    coulomb: EffectorHandle,
+    magnetic_field: EffectorHandle,
+    wind: EffectorHandle,
+    stashed_wind: WindSource,
+    pool: EffectorHandle,
+    pool_mode: usize,
+    conveyor: Option<EffectorHandle>,
+    last_spawned: Vec<EntityHandle>,
+    drag_joint: Option<JointHandle>,
+    falloff_mode: usize,
+    placed_fields: Vec<EffectorHandle>,
+    ship_controls: Vec<(EffectorHandle, Engine)>,
+    engines: [bool; 3],
+    charge_mode: usize,
+    snapshot: Option<Snapshot>,
+}
+
+impl Playground {
+    /// Takes over `world`, adding a switched off default for each toggled effector it lacks.
+    pub fn new(mut world: World, width: f32, height: f32) -> Self {
+        let gravity = find_or_add(&mut world, || UniformGravity::new(GRAVITY));
+        let mutual_gravity = find_or_add(&mut world, || MutualGravity::new(MUTUAL_G, MUTUAL_SOFTENING));
+        let coulomb = find_or_add(&mut world, || Coulomb::new(COULOMB_K, COULOMB_SOFTENING));
+        let magnetic_field = find_or_add(&mut world, || UniformMagneticField::new(MAGNETIC_FIELD));
+        let pool = find_or_add(&mut world, || {
+            FluidVolume::new(FluidRegion::Rectangle { min: Vec2::zeros(), max: Vec2::zeros() }, WATER_DENSITY, WATER_DRAG, GRAVITY)
+        });
+        let pool_mode = if world.is_enabled(pool) { 1 } else { 0 };
+        let conveyor = world.find_effector::<Conveyor>();
+
+        // Wind starts calm unless the world already has some
+        let wind = world.find_effector::<WindField>().unwrap_or_else(|| {
+            let wind = wind_field(&mut world, WindSource::Uniform(Vec2::zeros()));
+            world.add_effector(Box::new(wind))
+        });
+
+        Self {
+            world,
+            size: Vec2::new(width, height),
+            steps: 0,
+            gravity,
+            mutual_gravity,
+            coulomb,
+            magnetic_field,
+            wind,
+            stashed_wind: WindSource::Noise { base: Vec2::zeros(), amplitude: 2000.0, scale: 0.005, speed: 0.3 },
+            pool,
+            pool_mode,
+            conveyor,
+            last_spawned: vec![],
+            drag_joint: None,
+            falloff_mode: 0,
+            placed_fields: vec![],
+            ship_controls: vec![],
+            engines: [false; 3],
+            charge_mode: 0,
+            snapshot: None,
+        }
+    }
+
+    pub fn falloff_name(&self) -> &'static str {
+        FALLOFF_NAMES[self.falloff_mode]
+    }
+
+    pub fn spawn_charge(&self) -> f32 {
+        SPAWN_CHARGES[self.charge_mode]
+    }
+
+    /// Moves the world forward by a frame, returning the number of steps taken.
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        let steps = self.world.advance(frame_time);
+        self.steps += steps as u64;
+        steps
+    }
+
+    pub fn step(&mut self, delta: f32) {
+        self.world.step(delta);
+        self.steps += 1;
+    }
+
+    pub fn apply(&mut self, command: &Command) {
+        let world = &mut self.world;
+
+        match *command {
+            Command::Resize { width, height } => {
+                self.size = Vec2::new(width, height);
+                world.update_size(width, height);
+            }
+            Command::Pointer(pointer) => {
+                world.set_pointer(pointer);
+
+                if let Some(handle) = self.drag_joint {
+                    if let Some(joint) = world.get_joint_mut(handle) {
+                        if let Some(joint) = joint.as_any_mut().downcast_mut::<MouseJoint>() {
+                            joint.set_target(pointer);
+                        }
+                    } else {
+                        // The grabbed entity left the world and took the joint with it
+                        self.drag_joint = None;
+                    }
+                }
+            }
+            Command::Press(at) => {
+                // Grab a dynamic entity under the cursor, otherwise spawn a new circle
+                let grabbed = world.query_point(at).filter(|handle| {
+                    matches!(world.get_entity(*handle).unwrap().borrow().get_type(), EntityType::Dynamic)
+                });
+
+                if let Some(handle) = grabbed {
+                    let mass = world.get_entity(handle).unwrap().borrow().get_mass();
+                    self.drag_joint = Some(world.add_joint(Box::new(MouseJoint::new(world, handle, at, DRAG_MAX_FORCE * mass))));
+                } else {
+                    let mut e = CircleEntity::new(at.x, at.y);
+                    e.groups = BALL_GROUP;
+                    e.charge = SPAWN_CHARGES[self.charge_mode];
+                    e.tags.push(SPAWN_TAG.to_string());
+
+                    self.last_spawned.push(world.add_entity(Rc::new(RefCell::new(e))));
+                }
+            }
+            Command::Release => {
+                if let Some(handle) = self.drag_joint.take() {
+                    world.remove_joint(handle);
+                }
+            }
+            Command::SpawnCrate(at) => {
+                let mut e = RectangleEntity::new(at.x, at.y, 30.0, 30.0);
+                e.groups = CRATE_GROUP;
+                e.charge = SPAWN_CHARGES[self.charge_mode];
+                e.tags.push(SPAWN_TAG.to_string());
+
+                self.last_spawned.push(world.add_entity(Rc::new(RefCell::new(e))));
+            }
+            Command::Spring { stiff } => {
+                if let Some((a, b)) = self.last_pair() {
+                    if stiff {
+                        self.world.add_joint(Box::new(SpringJoint::new_stiff(a, b, SPRING_REST_LENGTH, 2.0, 0.3)));
+                    } else {
+                        self.world.add_joint(Box::new(SpringJoint::new(a, b, SPRING_REST_LENGTH, 20.0, 1.0)));
+                    }
+                }
+            }
+            // Motors and limits make a flipper out of the pin
+            Command::Pin { at, motor, limited } => {
+                if let Some((a, b)) = self.last_pair() {
+                    let mut joint = RevoluteJoint::new(&self.world, a, b, at);
+
+                    if motor {
+                        joint = joint.with_motor(MOTOR_SPEED, MOTOR_MAX_TORQUE);
+                    }
+
+                    if limited {
+                        joint = joint.with_limits(-FRAC_PI_4, FRAC_PI_4);
+                    }
+
+                    self.world.add_joint(Box::new(joint));
+                }
+            }
+            // Slides along the line between the centres
+            Command::Slider { motor, limited } => {
+                if let Some((a, b)) = self.last_pair() {
+                    let world = &mut self.world;
+                    let (center_a, center_b) = match (world.get_entity(a), world.get_entity(b)) {
+                        (Some(a), Some(b)) => (a.borrow().get_center(), b.borrow().get_center()),
+                        _ => (Vec2::zeros(), Vec2::zeros()),
+                    };
+                    let axis = center_b - center_a;
+
+                    if axis.length() > 0.0 {
+                        let mut joint = PrismaticJoint::new(world, a, b, center_b, axis);
+
+                        if motor {
+                            joint = joint.with_motor(SLIDER_SPEED, SLIDER_MAX_FORCE);
+                        }
+
+                        if limited {
+                            joint = joint.with_limits(-SLIDER_TRAVEL, SLIDER_TRAVEL);
+                        }
+
+                        world.add_joint(Box::new(joint));
+                    }
+                }
+            }
+            Command::Weld { at, soft } => {
+                if let Some((a, b)) = self.last_pair() {
+                    let mut joint = WeldJoint::new(&self.world, a, b, at);
+
+                    if soft {
+                        joint = joint.with_softness(WELD_FREQUENCY, WELD_DAMPING_RATIO);
+                    }
+
+                    self.world.add_joint(Box::new(joint));
+                }
+            }
+            Command::ToggleGravity => toggle(world, self.gravity),
+            // Limiting mutual gravity to the balls leaves crates out of it
+            Command::ToggleMutualGravity { balls_only } => {
+                toggle(world, self.mutual_gravity);
+
+                if let Some(gravity) = effector_mut::<MutualGravity>(world, self.mutual_gravity) {
+                    gravity.selector = if balls_only { Selector::Groups(BALL_GROUP) } else { Selector::AllDynamic };
+                }
+            }
+            Command::CycleCharge => self.charge_mode = (self.charge_mode + 1) % SPAWN_CHARGES.len(),
+            Command::ToggleCoulomb => toggle(world, self.coulomb),
+            Command::ToggleMagneticField => toggle(world, self.magnetic_field),
+            // Flipping the field makes orbits turn the other way
+            Command::ReverseMagneticField => {
+                if let Some(field) = effector_mut::<UniformMagneticField>(world, self.magnetic_field) {
+                    field.b = -field.b;
+                }
+            }
+            Command::SwapWind => {
+                if let Some(wind) = effector_mut::<WindField>(world, self.wind) {
+                    std::mem::swap(&mut wind.source, &mut self.stashed_wind);
+                }
+            }
+            Command::Gust { at } => {
+                let direction = if at.x < self.size.x / 2.0 { 1.0 } else { -1.0 };
+                let gust = wind_field(world, WindSource::Uniform(Vec2::new(GUST_SPEED * direction, 0.0)));
+
+                let gust = world.add_effector(Box::new(gust));
+                world.set_time_to_live(gust, Some(GUST_DURATION));
+            }
+            // Cycles between a drained pool, a rectangular pool and a sloped basin
+            Command::CyclePool => {
+                self.pool_mode = (self.pool_mode + 1) % 3;
+                let Vec2 { x: w, y: h } = self.size;
+
+                world.set_enabled(self.pool, self.pool_mode != 0);
+                if let Some(pool) = effector_mut::<FluidVolume>(world, self.pool) {
+                    pool.surface_level = h * 0.6;
+                    pool.region = if self.pool_mode == 2 {
+                        FluidRegion::Polygon(vec![
+                            Vec2::new(0.0, h * 0.4),
+                            Vec2::new(w, h * 0.4),
+                            Vec2::new(w * 0.75, h),
+                            Vec2::new(w * 0.25, h),
+                        ])
+                    } else {
+                        FluidRegion::Rectangle { min: Vec2::new(0.0, h * 0.4), max: Vec2::new(w, h) }
+                    };
+                }
+            }
+            Command::CycleFalloff => self.falloff_mode = (self.falloff_mode + 1) % FALLOFF_NAMES.len(),
+            Command::Attractor { at, repel, anchor } => {
+                let source = match anchor {
+                    AttractorAnchor::Fixed => Some(AttractorSource::Fixed(at)),
+                    AttractorAnchor::LastSpawned => self.last_spawned.last().map(|handle| AttractorSource::Entity(*handle)),
+                    AttractorAnchor::Pointer => Some(AttractorSource::Mouse),
+                };
+
+                if let Some(source) = source {
+                    let (falloff, strength) = make_falloff(self.falloff_mode);
+                    let attractor = PointAttractor::new(source, falloff, strength)
+                        .with_radius(ATTRACTOR_MIN_RADIUS, ATTRACTOR_MAX_RADIUS)
+                        .with_repel(repel);
+
+                    self.placed_fields.push(world.add_effector(Box::new(attractor)));
+                }
+            }
+            Command::ClearFields => {
+                for field in self.placed_fields.drain(..) {
+                    world.remove_effector(field);
+                }
+            }
+            Command::Vortex { at, reverse } => {
+                let strength = if reverse { -VORTEX_STRENGTH } else { VORTEX_STRENGTH };
+                let vortex = Vortex::new(at, strength, VORTEX_RADIUS).with_pull(VORTEX_PULL);
+
+                self.placed_fields.push(world.add_effector(Box::new(vortex)));
+            }
+            // Fan blowing upwards, with `at` at the bottom of its column
+            Command::Fan { at } => {
+                let min = Vec2::new(at.x - FAN_SIZE.x / 2.0, at.y - FAN_SIZE.y);
+                let fan = PushZone::new(min, min + FAN_SIZE, FAN_ACCELERATION);
+
+                self.placed_fields.push(world.add_effector(Box::new(fan)));
+            }
+            Command::ToggleConveyor => {
+                if let Some(conveyor) = self.conveyor {
+                    toggle(world, conveyor);
+                }
+            }
+            Command::ReverseConveyor => {
+                if let Some(belt) = self.conveyor.and_then(|conveyor| effector_mut::<Conveyor>(world, conveyor)) {
+                    belt.speed = -belt.speed;
+                }
+            }
+            // Ship with a main engine in its tail and steering jets in its nose
+            Command::Ship { at } => {
+                let mut ship = RectangleEntity::new(at.x - SHIP_SIZE.x / 2.0, at.y - SHIP_SIZE.y / 2.0, SHIP_SIZE.x, SHIP_SIZE.y);
+                ship.max_linear_speed = SHIP_MAX_SPEED;
+                ship.max_force = SHIP_MAX_FORCE;
+                let ship = world.add_entity(Rc::new(RefCell::new(ship)));
+
+                let (tail, nose) = (Vec2::new(0.0, SHIP_SIZE.y / 2.0), Vec2::new(0.0, -SHIP_SIZE.y / 2.0));
+                let thrusters = [
+                    (Thruster::new(ship, tail, Vec2::new(0.0, -1.0), MAIN_ENGINE_THRUST), Engine::Main),
+                    (Thruster::new(ship, nose, Vec2::new(-1.0, 0.0), STEERING_THRUST), Engine::Left),
+                    (Thruster::new(ship, nose, Vec2::new(1.0, 0.0), STEERING_THRUST), Engine::Right),
+                ];
+
+                for (thruster, engine) in thrusters {
+                    self.ship_controls.push((world.add_effector(Box::new(thruster)), engine));
+                }
+                self.set_throttles();
+            }
+            Command::Engines { main, left, right } => {
+                self.engines = [main, left, right];
+                self.set_throttles();
+            }
+            // Blasts go first so drag and buoyancy already see the kicked velocities
+            Command::Explosion { at, kind, occlusion } => {
+                let falloff = match kind {
+                    BlastKind::Linear => BlastFalloff::Linear,
+                    BlastKind::Quadratic => BlastFalloff::Quadratic,
+                    BlastKind::Constant => BlastFalloff::Constant,
+                };
+                let explosion = Explosion::new(at, EXPLOSION_STRENGTH, EXPLOSION_RADIUS)
+                    .with_falloff(falloff)
+                    .with_occlusion(occlusion)
+                    .with_lifetime(EXPLOSION_LIFETIME);
+
+                let explosion = world.add_effector(Box::new(explosion));
+                world.set_priority(explosion, EXPLOSION_PRIORITY);
+            }
+            Command::Snapshot => self.snapshot = Some(world.snapshot()),
+            // Rewinding forgets whatever was spawned or placed since the snapshot
+            Command::Rewind => {
+                if let Some(snapshot) = &self.snapshot {
+                    world.restore(snapshot);
+
+                    self.drag_joint = None;
+                    self.last_spawned.retain(|handle| world.get_entity(*handle).is_some());
+                    self.placed_fields.retain(|handle| world.get_effector_mut(*handle).is_some());
+                    self.ship_controls.retain(|(handle, _)| world.get_effector_mut(*handle).is_some());
+                }
+            }
+        }
+    }
+
+    /// The two most recently spawned entities, which the joint tools connect.
+    fn last_pair(&self) -> Option<(EntityHandle, EntityHandle)> {
+        match self.last_spawned[..] {
+            [.., a, b] => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// Also forgets the thrusters of ships that have left the world.
+    fn set_throttles(&mut self) {
+        let world = &mut self.world;
+        self.ship_controls.retain(|(handle, _)| world.get_effector_mut(*handle).is_some());
+
+        for (handle, engine) in &self.ship_controls {
+            let firing = self.engines[*engine as usize];
+
+            if let Some(thruster) = effector_mut::<Thruster>(&mut self.world, *handle) {
+                thruster.set_throttle(if firing { 1.0 } else { 0.0 });
+            }
+        }
+    }
+}
+
+/// The room the playground opens with: walls on every side, mouse gravity on spawned bodies,
+/// light air drag and the switched off effectors the commands toggle.
+pub fn default_world(width: f32, height: f32) -> World {
+    let mut world = World::new(height, width);
+    world.add_effector(Box::new(MouseGravity::new(0.1).with_selector(Selector::Tag(SPAWN_TAG.to_string()))));
+
+    // Gravity and mutual gravity start switched off
+    find_or_add(&mut world, || UniformGravity::new(GRAVITY));
+    find_or_add(&mut world, || MutualGravity::new(MUTUAL_G, MUTUAL_SOFTENING));
+
+    // Electromagnetism is off as well
+    find_or_add(&mut world, || Coulomb::new(COULOMB_K, COULOMB_SOFTENING));
+    find_or_add(&mut world, || UniformMagneticField::new(MAGNETIC_FIELD));
+
+    world.add_effector(Box::new(Drag::new(AIR_DRAG.linear, AIR_DRAG.quadratic, ANGULAR_DRAG)));
+
+    // Adding world bounding boxes
+    let bb1 = RectangleEntity::new_static(0.0, 0.0, BB_WIDTH, height);
+    let bb2 = RectangleEntity::new_static(0.0, 0.0, width, BB_WIDTH);
+    let bb3 = RectangleEntity::new_static(0.0, height - BB_WIDTH, width, BB_WIDTH);
+    let bb4 = RectangleEntity::new_static(width - BB_WIDTH, 0.0, BB_WIDTH, height);
+
+    world.add_entity(Rc::new(RefCell::new(bb1)));
+    world.add_entity(Rc::new(RefCell::new(bb2)));
+    let floor = world.add_entity(Rc::new(RefCell::new(bb3)));
+    world.add_entity(Rc::new(RefCell::new(bb4)));
+
+    // The floor doubles as a conveyor belt
+    find_or_add(&mut world, || Conveyor::new(floor, CONVEYOR_SPEED));
+
+    world
+}
+
+/// Sets the source of the world's wind, adding a wind field if it has none.
+pub fn set_wind(world: &mut World, source: WindSource) {
+    match world.find_effector::<WindField>().and_then(|wind| effector_mut::<WindField>(world, wind)) {
+        Some(wind) => wind.source = source,
+        None => {
+            let wind = wind_field(world, source);
+            world.add_effector(Box::new(wind));
+        }
+    }
+}
+
+/// Wind blowing over the world's drag, or bringing its own air drag when the world has none.
+fn wind_field(world: &mut World, source: WindSource) -> WindField {
+    match world.find_effector::<Drag>().and_then(|drag| effector_mut::<Drag>(world, drag)) {
+        Some(drag) => WindField::with_drag(source, drag),
+        None => WindField::new(source, AIR_DRAG),
+    }
+}
+
+/// The world's first effector of type `T`, adding a disabled one if there is none.
+fn find_or_add<T: Effector>(world: &mut World, effector: impl FnOnce() -> T) -> EffectorHandle {
+    world.find_effector::<T>().unwrap_or_else(|| {
+        let handle = world.add_effector(Box::new(effector()));
+        world.set_enabled(handle, false);
+        handle
+    })
+}
+
+fn toggle(world: &mut World, handle: EffectorHandle) {
+    world.set_enabled(handle, !world.is_enabled(handle));
+}
+
+fn effector_mut<T: Effector>(world: &mut World, handle: EffectorHandle) -> Option<&mut T> {
+    world.get_effector_mut(handle)?.as_any_mut().downcast_mut::<T>()
+}
+
+/// Falloff law for each entry in `FALLOFF_NAMES`, with a strength giving
+/// roughly the same pull at a hundred pixels.
+fn make_falloff(mode: usize) -> (Falloff, f32) {
+    match mode {
+        0 => (Falloff::InverseSquare, 2.0e6),
+        1 => (Falloff::LinearSpring, 2.0),
+        2 => (Falloff::Constant, 200.0),
+        _ => (Falloff::Custom(Rc::new(|r| (1.0 - r / ATTRACTOR_MAX_RADIUS).max(0.0))), 270.0),
+    }
+}
@@ -0,0 +1,129 @@
+use std::io;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::playground::{Command, Playground};
+use crate::world::scene::{invalid, read_file, write_file, Scene};
+
+/// Bumped whenever a change to the format would make older recordings play back differently.
+pub const RECORDING_VERSION: u32 = 1;
+
+/// A session's starting scene and every command applied to it, each tagged with the number of
+/// steps taken before it. Recordings always run at a fixed timestep so they play back exactly.
+#[derive(Serialize, Deserialize)]
+pub struct Recording {
+    pub version: u32,
+    pub fixed_delta: f32,
+    pub width: f32,
+    pub height: f32,
+    pub scene: Scene,
+    pub commands: Vec<(u64, Command)>,
+    /// Length of the recording in steps.
+    pub steps: u64,
+    /// World state hash once all the steps have been played, to check a playback against.
+    pub state_hash: Option<u64>,
+}
+
+impl Recording {
+    pub fn new(scene: Scene, fixed_delta: f32, width: f32, height: f32) -> Self {
+        Self {
+            version: RECORDING_VERSION,
+            fixed_delta,
+            width,
+            height,
+            scene,
+            commands: vec![],
+            steps: 0,
+            state_hash: None,
+        }
+    }
+
+    /// Reads a recording, as JSON when the file has a `.json` extension and as RON otherwise.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let recording: Recording = read_file(path)?;
+
+        if recording.version != RECORDING_VERSION {
+            return Err(invalid(format!(
+                "unsupported recording version {}, expected {}",
+                recording.version, RECORDING_VERSION
+            )));
+        }
+
+        Ok(recording)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        write_file(self, path)
+    }
+
+    /// The playground the recording starts from. Recording sessions run on this too, so that
+    /// the live world and its playback are built the same way.
+    pub fn playground(&self) -> io::Result<Playground> {
+        let mut world = self.scene.build()?;
+        world.set_fixed_timestep(Some(self.fixed_delta));
+
+        Ok(Playground::new(world, self.width, self.height))
+    }
+
+    pub fn record(&mut self, step: u64, command: &Command) {
+        self.commands.push((step, command.clone()));
+    }
+
+    /// Marks the recording as ending at the playground's current step and state.
+    pub fn finish(&mut self, playground: &Playground) {
+        self.steps = playground.steps;
+        self.state_hash = Some(playground.world.state_hash());
+    }
+}
+
+/// Plays a recording back one step at a time.
+pub struct Replay {
+    pub recording: Recording,
+    next: usize,
+}
+
+impl Replay {
+    pub fn new(recording: Recording) -> Self {
+        Self { recording, next: 0 }
+    }
+
+    pub fn is_finished(&self, playground: &Playground) -> bool {
+        playground.steps >= self.recording.steps
+    }
+
+    /// Applies the commands due before the playground's next step, then takes that step.
+    pub fn play_step(&mut self, playground: &mut Playground) {
+        self.apply_due(playground);
+        playground.step(self.recording.fixed_delta);
+    }
+
+    /// Applies the commands recorded at or before the playground's current step. Once the last step
+    /// has been played this also catches up with the commands given just before the recording ended.
+    pub fn apply_due(&mut self, playground: &mut Playground) {
+        while let Some((step, command)) = self.recording.commands.get(self.next) {
+            if *step > playground.steps {
+                break;
+            }
+
+            playground.apply(command);
+            self.next += 1;
+        }
+    }
+
+    /// Whether the playground ended up where the recording did, `None` if the recording has no hash.
+    pub fn matches(&self, playground: &Playground) -> Option<bool> {
+        self.recording.state_hash.map(|hash| hash == playground.world.state_hash())
+    }
+}
+
+/// Plays a recording from start to finish without a window.
+pub fn play_headless(recording: Recording) -> io::Result<(Playground, Replay)> {
+    let mut playground = recording.playground()?;
+    let mut replay = Replay::new(recording);
+
+    while !replay.is_finished(&playground) {
+        replay.play_step(&mut playground);
+    }
+    replay.apply_due(&mut playground);
+
+    Ok((playground, replay))
+}
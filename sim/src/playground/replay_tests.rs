@@ -0,0 +1,89 @@
+use super::replay::{play_headless, Recording, RECORDING_VERSION};
+use super::{default_world, BlastKind, Command};
+use crate::world::scene::Scene;
+use common::math::vectors::Vec2;
+use std::fs;
+use std::io;
+
+const FIXED_DELTA: f32 = 1.0 / 60.0;
+const WIDTH: f32 = 800.0;
+const HEIGHT: f32 = 600.0;
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("physics-playground-{}-{}", std::process::id(), name))
+}
+
+fn new_recording() -> Recording {
+    Recording::new(Scene::describe(&default_world(WIDTH, HEIGHT)), FIXED_DELTA, WIDTH, HEIGHT)
+}
+
+/// A short session, as (frame, command) pairs.
+fn session() -> Vec<(usize, Command)> {
+    vec![
+        (0, Command::Pointer(Vec2::new(400.0, 100.0))),
+        (2, Command::Press(Vec2::new(300.0, 200.0))),
+        (2, Command::Release),
+        (5, Command::Press(Vec2::new(340.0, 200.0))),
+        (5, Command::Release),
+        (6, Command::Spring { stiff: true }),
+        (9, Command::ToggleGravity),
+        (12, Command::SpawnCrate(Vec2::new(500.0, 150.0))),
+        (15, Command::Pointer(Vec2::new(200.0, 300.0))),
+        (20, Command::Explosion { at: Vec2::new(320.0, 260.0), kind: BlastKind::Linear, occlusion: true }),
+        (30, Command::ToggleGravity),
+    ]
+}
+
+#[test]
+fn test_saved_recording_loads_back_unchanged() {
+    let mut recording = new_recording();
+    for (step, command) in session() {
+        recording.record(step as u64, &command);
+    }
+
+    let path = temp_path("recording.ron");
+    recording.save(&path).unwrap();
+    let loaded = Recording::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.commands, recording.commands);
+    assert_eq!(loaded.fixed_delta, FIXED_DELTA);
+    assert_eq!((loaded.width, loaded.height), (WIDTH, HEIGHT));
+    assert_eq!(loaded.scene.entities.len(), recording.scene.entities.len());
+}
+
+#[test]
+fn test_playback_reproduces_a_live_session() {
+    let mut recording = new_recording();
+    let mut live = recording.playground().unwrap();
+    let mut commands = session().into_iter().peekable();
+
+    // Uneven frame times, so commands land between steps and some frames take several steps or none
+    for frame in 0..40 {
+        while let Some((_, command)) = commands.next_if(|(at, _)| *at == frame) {
+            recording.record(live.steps, &command);
+            live.apply(&command);
+        }
+        live.advance([0.01, 0.03, 0.02, 0.005][frame % 4]);
+    }
+    recording.finish(&live);
+    assert!(recording.steps > 0);
+
+    let (played, replay) = play_headless(recording).unwrap();
+    assert_eq!(played.steps, live.steps);
+    assert_eq!(played.world.state_hash(), live.world.state_hash());
+    assert_eq!(replay.matches(&played), Some(true));
+}
+
+#[test]
+fn test_load_rejects_other_versions() {
+    let mut recording = new_recording();
+    recording.version = RECORDING_VERSION + 1;
+
+    let path = temp_path("future-recording.ron");
+    recording.save(&path).unwrap();
+    let err = Recording::load(&path).err().unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+}
@@ -0,0 +1,38 @@
+use crate::world::stats::StepStats;
+use macroquad::color::{GRAY, GREEN, WHITE};
+use macroquad::shapes::draw_rectangle;
+use macroquad::text::draw_text;
+
+const LINE_HEIGHT: f32 = 18.0;
+const FONT_SIZE: f32 = 18.0;
+const BAR_WIDTH: f32 = 150.0;
+
+/// Draws the last step's phase timings with a bar for each one's share of the frame, followed by the counts.
+pub fn draw_step_stats(stats: &StepStats, x: f32, y: f32) {
+    let phases = stats.phases();
+    let frame = (stats.step_time() + stats.render).as_secs_f32().max(f32::EPSILON);
+    let mut line_y = y;
+
+    draw_text(&format!("step {:.2} ms", stats.step_time().as_secs_f32() * 1000.0), x, line_y, FONT_SIZE, WHITE);
+
+    for (name, time) in phases {
+        line_y += LINE_HEIGHT;
+        let share = time.as_secs_f32() / frame;
+
+        draw_rectangle(x, line_y - FONT_SIZE * 0.6, BAR_WIDTH, FONT_SIZE * 0.6, GRAY);
+        draw_rectangle(x, line_y - FONT_SIZE * 0.6, BAR_WIDTH * share, FONT_SIZE * 0.6, GREEN);
+        draw_text(&format!("{} {:.3} ms", name, time.as_secs_f32() * 1000.0), x + BAR_WIDTH + 10.0, line_y, FONT_SIZE, WHITE);
+    }
+
+    line_y += LINE_HEIGHT;
+    draw_text(
+        &format!(
+            "{} bodies, {} pairs, {} contacts, {} solver iterations",
+            stats.bodies, stats.pairs, stats.contacts, stats.solver_iterations
+        ),
+        x,
+        line_y,
+        FONT_SIZE,
+        WHITE,
+    );
+}
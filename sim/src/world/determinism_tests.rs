@@ -0,0 +1,71 @@
+use super::World;
+use crate::effectors::forces::coulomb::Coulomb;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::joints::revolute::RevoluteJoint;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Exactly representable in binary, so frame times of any power of two split it without rounding.
+const FIXED_DELTA: f32 = 1.0 / 64.0;
+
+fn pile() -> World {
+    let mut world = World::new(1000.0, 1000.0);
+    world.set_fixed_timestep(Some(FIXED_DELTA));
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 600.0, 1000.0, 20.0))));
+
+    for i in 0..6 {
+        let mut ball = CircleEntity::new(300.0 + i as f32 * 25.0, 500.0 - i as f32 * 30.0);
+        ball.charge = if i % 2 == 0 { 1.0 } else { -1.0 };
+        world.add_entity(Rc::new(RefCell::new(ball)));
+    }
+
+    let crate_a = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(500.0, 400.0, 30.0, 30.0))));
+    let crate_b = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(540.0, 400.0, 30.0, 30.0))));
+    world.add_joint(Box::new(RevoluteJoint::new(&world, crate_a, crate_b, Vec2::new(535.0, 415.0))));
+
+    world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 200.0))));
+    world.add_effector(Box::new(Coulomb::new(2.0e5, 10.0)));
+    world
+}
+
+#[test]
+fn test_fixed_timestep_ignores_frame_rate() {
+    let (mut slow, mut fast) = (pile(), pile());
+
+    for _ in 0..64 {
+        slow.advance(1.0 / 32.0);
+    }
+    for _ in 0..256 {
+        fast.advance(1.0 / 128.0);
+    }
+
+    assert_eq!(slow.state_hash(), fast.state_hash());
+}
+
+#[test]
+fn test_state_hash_sees_the_smallest_change() {
+    let (a, b) = (pile(), pile());
+    assert_eq!(a.state_hash(), b.state_hash());
+
+    let ball = a.entities.values().nth(1).unwrap();
+    let x = ball.borrow().get_position().x;
+    ball.borrow_mut().get_position_mut().x = f32::from_bits(x.to_bits() + 1);
+
+    assert_ne!(a.state_hash(), b.state_hash());
+}
+
+#[test]
+fn test_advance_caps_substeps() {
+    let mut world = pile();
+
+    assert_eq!(world.advance(FIXED_DELTA / 2.0), 0);
+    assert_eq!(world.advance(FIXED_DELTA / 2.0), 1);
+    assert_eq!(world.advance(1.0), 8);
+    assert_eq!(world.advance(0.0), 1);
+
+    world.set_fixed_timestep(None);
+    assert_eq!(world.advance(1.0), 1);
+}
@@ -0,0 +1,54 @@
+use common::math::vectors::Vec2;
+use crate::entity::EntityType;
+use crate::world::World;
+
+/// Conserved quantities and losses over the whole world, for checking the integrator and collision
+/// response. Momenta only count dynamic entities; static ones act as an outside body with infinite mass.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Diagnostics {
+    pub kinetic_energy: f32,
+    /// Stored in the fields of conservative effectors: uniform gravity, mutual gravity and Coulomb.
+    pub potential_energy: f32,
+    /// Stored in stretched springs and other joints.
+    pub joint_energy: f32,
+    pub linear_momentum: Vec2,
+    /// About the world origin, orbital plus spin.
+    pub angular_momentum: f32,
+    /// Kinetic energy lost to restitution in the last step's collisions.
+    pub collision_loss: f32,
+}
+
+impl Diagnostics {
+    pub fn total_energy(&self) -> f32 {
+        self.kinetic_energy + self.potential_energy + self.joint_energy
+    }
+}
+
+impl World {
+    pub fn diagnostics(&self) -> Diagnostics {
+        let mut diagnostics = Diagnostics {
+            potential_energy: self.effectors.iter()
+                .filter(|slot| slot.enabled)
+                .map(|slot| slot.effector.potential_energy(&slot.effector.get_selector().resolve(&self.entities)))
+                .sum(),
+            joint_energy: self.joint_energy(),
+            collision_loss: self.collision_loss,
+            ..Diagnostics::default()
+        };
+
+        for entity in self.entities.values() {
+            let entity = entity.borrow();
+            if let EntityType::Static = entity.get_type() {
+                continue;
+            }
+
+            let momentum = entity.get_velocity() * entity.get_mass();
+            diagnostics.kinetic_energy += entity.get_kinetic_energy();
+            diagnostics.linear_momentum += momentum;
+            diagnostics.angular_momentum += entity.get_center().cross(&momentum)
+                + entity.get_inertia() * entity.get_angular_velocity();
+        }
+
+        diagnostics
+    }
+}
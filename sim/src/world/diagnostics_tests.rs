@@ -0,0 +1,82 @@
+use super::{EntityHandle, World};
+use crate::effectors::forces::coulomb::Coulomb;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::entity::circle_entity::CircleEntity;
+use crate::joints::spring::SpringJoint;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DELTA: f32 = 1.0 / 60.0;
+
+fn ball(world: &mut World, position: Vec2, velocity: Vec2) -> EntityHandle {
+    charged_ball(world, position, velocity, 0.0)
+}
+
+fn charged_ball(world: &mut World, position: Vec2, velocity: Vec2, charge: f32) -> EntityHandle {
+    let mut e = CircleEntity::new(position.x, position.y);
+    e.velocity = velocity;
+    e.charge = charge;
+    world.add_entity(Rc::new(RefCell::new(e)))
+}
+
+#[test]
+fn test_free_fall_conserves_total_energy() {
+    let mut world = World::new(1000.0, 1000.0);
+    ball(&mut world, Vec2::new(500.0, 100.0), Vec2::new(30.0, 0.0));
+    world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 200.0))));
+
+    let start = world.diagnostics();
+    for _ in 0..60 {
+        world.step(DELTA);
+    }
+    let end = world.diagnostics();
+
+    assert!(end.kinetic_energy > start.kinetic_energy);
+    assert!(end.potential_energy < start.potential_energy);
+    assert!((end.total_energy() - start.total_energy()).abs() < 1e-4 * start.potential_energy.abs());
+}
+
+#[test]
+fn test_collision_loss_accounts_for_lost_kinetic_energy() {
+    let mut world = World::new(1000.0, 1000.0);
+    ball(&mut world, Vec2::new(480.0, 500.0), Vec2::new(100.0, 0.0));
+    ball(&mut world, Vec2::new(520.0, 500.0), Vec2::new(-50.0, 0.0));
+
+    let before = world.diagnostics();
+    let mut lost = 0.0;
+    for _ in 0..30 {
+        world.step(DELTA);
+        lost += world.diagnostics().collision_loss;
+    }
+    let after = world.diagnostics();
+
+    assert!(lost > 0.0);
+    assert!((before.kinetic_energy - after.kinetic_energy - lost).abs() < 1e-2);
+    assert!((after.linear_momentum - before.linear_momentum).length() < 1e-3);
+}
+
+#[test]
+fn test_angular_momentum_counts_orbit_and_spin() {
+    let mut world = World::new(1000.0, 1000.0);
+    let handle = ball(&mut world, Vec2::new(0.0, 10.0), Vec2::new(5.0, 0.0));
+    let entity = world.get_entity(handle).unwrap().clone();
+    *entity.borrow_mut().get_angular_velocity_mut() = 2.0;
+
+    let entity = entity.borrow();
+    let expected = -10.0 * 5.0 * entity.get_mass() + entity.get_inertia() * 2.0;
+    assert_eq!(world.diagnostics().angular_momentum, expected);
+}
+
+#[test]
+fn test_potential_energy_from_springs_and_charges() {
+    let mut world = World::new(1000.0, 1000.0);
+    let a = charged_ball(&mut world, Vec2::new(100.0, 100.0), Vec2::zeros(), 1.0);
+    let b = charged_ball(&mut world, Vec2::new(200.0, 100.0), Vec2::zeros(), 1.0);
+    world.add_joint(Box::new(SpringJoint::new(a, b, 60.0, 20.0, 1.0)));
+    assert!(world.diagnostics().joint_energy > 0.0);
+    assert_eq!(world.diagnostics().potential_energy, 0.0);
+
+    world.add_effector(Box::new(Coulomb::new(1000.0, 0.0)));
+    assert_eq!(world.diagnostics().potential_energy, 10.0);
+}
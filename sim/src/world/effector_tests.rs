@@ -0,0 +1,106 @@
+use super::{EntityMap, EntityRef, World};
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::Entity;
+use common::math::vectors::Vec2;
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn world_with_ball() -> (World, Rc<RefCell<CircleEntity>>) {
+    let mut world = World::new(1000.0, 1000.0);
+    let ball = Rc::new(RefCell::new(CircleEntity::new(500.0, 500.0)));
+    world.add_entity(ball.clone());
+    (world, ball)
+}
+
+#[test]
+fn test_disabled_effectors_are_skipped() {
+    let (mut world, ball) = world_with_ball();
+    let gravity = world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 10.0))));
+
+    world.set_enabled(gravity, false);
+    world.update(1.0);
+    assert_eq!(ball.borrow().get_force().y, 0.0);
+
+    world.set_enabled(gravity, true);
+    world.update(1.0);
+    assert_eq!(ball.borrow().get_force().y, 20.0);
+
+    assert!(world.remove_effector(gravity).is_some());
+    assert!(world.remove_effector(gravity).is_none());
+}
+
+#[test]
+fn test_time_to_live_counts_enabled_updates() {
+    let (mut world, _) = world_with_ball();
+    let gravity = world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 10.0))));
+    world.set_time_to_live(gravity, Some(0.25));
+
+    world.update(0.1);
+    world.set_enabled(gravity, false);
+    world.update(1.0);
+    world.update(0.1);
+    assert!(world.get_effector_mut(gravity).is_some());
+
+    world.set_enabled(gravity, true);
+    world.update(0.1);
+    world.update(0.1);
+    assert!(world.get_effector_mut(gravity).is_none());
+}
+
+#[derive(Clone)]
+struct Recorder {
+    id: u32,
+    log: Rc<RefCell<Vec<u32>>>,
+    selector: Selector,
+}
+
+impl Effector for Recorder {
+    fn update(&mut self, _targets: &[EntityRef], _entities: &EntityMap, _delta: f32) {
+        self.log.borrow_mut().push(self.id);
+    }
+
+    fn get_selector(&self) -> &Selector {
+        &self.selector
+    }
+
+    fn get_selector_mut(&mut self) -> &mut Selector {
+        &mut self.selector
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn clone_box(&self) -> Box<dyn Effector> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_priority_orders_updates() {
+    let mut world = World::new(1000.0, 1000.0);
+    let log = Rc::new(RefCell::new(vec![]));
+    let recorder = |id| Box::new(Recorder { id, log: Rc::clone(&log), selector: Selector::AllDynamic });
+
+    let first = world.add_effector(recorder(0));
+    world.add_effector(recorder(1));
+    let third = world.add_effector(recorder(2));
+
+    world.update(0.0);
+    assert_eq!(*log.borrow(), vec![0, 1, 2]);
+
+    // Higher priorities run later, ties keep insertion order
+    log.borrow_mut().clear();
+    world.set_priority(first, 1);
+    world.set_priority(third, -1);
+    world.update(0.0);
+    assert_eq!(*log.borrow(), vec![2, 1, 0]);
+}
@@ -1,29 +1,109 @@
 use std::cell::RefCell;
-use std::collections::LinkedList;
-use std::rc::{Rc, Weak};
+use std::collections::BTreeMap;
+use std::rc::Rc;
 use macroquad::color::WHITE;
 use macroquad::prelude::draw_text;
-use macroquad::time::get_frame_time;
 use common::math::vectors::Vec2;
 use crate::collisions::response::handle_collision;
 use crate::effectors::Effector;
 use crate::entity::{Entity, EntityType};
+use crate::joints::Joint;
+use crate::world::stats::StepStats;
+use std::time::{Duration, Instant};
+
+pub mod diagnostics;
+pub mod scene;
+pub mod snapshot;
+pub mod stats;
+
+#[cfg(test)]
+mod determinism_tests;
+#[cfg(test)]
+mod diagnostics_tests;
+#[cfg(test)]
+mod effector_tests;
+#[cfg(test)]
+mod scene_tests;
+#[cfg(test)]
+mod snapshot_tests;
+#[cfg(test)]
+mod stats_tests;
 
 pub type EntityRef = Rc<RefCell<dyn Entity>>;
-pub type WeakEntityRef = Weak<RefCell<dyn Entity>>;
+pub type EntityMap = BTreeMap<EntityHandle, EntityRef>;
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+const DEFAULT_SOLVER_ITERATIONS: usize = 10;
+/// Most fixed steps `advance` takes in one call, a slow frame drops the rest of its time instead of spiralling.
+const MAX_SUBSTEPS: u32 = 8;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EntityHandle(pub u32);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JointHandle(pub u32);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EffectorHandle(pub u32);
+
+struct EffectorSlot {
+    handle: EffectorHandle,
+    effector: Box<dyn Effector>,
+    enabled: bool,
+    priority: i32,
+    /// Seconds of enabled updates left before the effector is removed.
+    time_to_live: Option<f32>,
+}
+
+impl Clone for EffectorSlot {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            effector: self.effector.clone_box(),
+            enabled: self.enabled,
+            priority: self.priority,
+            time_to_live: self.time_to_live,
+        }
+    }
+}
 
 pub struct World {
     size: Vec2,
-    pub entities: Vec<EntityRef>,
-    pub effectors: LinkedList<Box<dyn Effector>>
+    next_handle: u32,
+    next_joint_handle: u32,
+    next_effector_handle: u32,
+    pub entities: EntityMap,
+    /// Kept sorted by priority, then by insertion.
+    effectors: Vec<EffectorSlot>,
+    pub joints: BTreeMap<JointHandle, Box<dyn Joint>>,
+    pointer: Vec2,
+    fixed_delta: Option<f32>,
+    /// Velocity passes the joint solver makes each step.
+    solver_iterations: usize,
+    /// Frame time not yet consumed by fixed steps.
+    accumulator: f32,
+    /// Kinetic energy taken out by collisions during the last step.
+    collision_loss: f32,
+    stats: StepStats,
 }
 
 impl World {
     pub fn new(height: f32, width: f32) -> Self {
         World {
             size: Vec2::new(width, height),
-            entities: Vec::new(),
-            effectors: LinkedList::new(),
+            next_handle: 0,
+            next_joint_handle: 0,
+            next_effector_handle: 0,
+            entities: BTreeMap::new(),
+            effectors: vec![],
+            joints: BTreeMap::new(),
+            pointer: Vec2::zeros(),
+            fixed_delta: None,
+            solver_iterations: DEFAULT_SOLVER_ITERATIONS,
+            accumulator: 0.0,
+            collision_loss: 0.0,
+            stats: StepStats::default(),
         }
     }
 
@@ -31,22 +111,211 @@ impl World {
         self.size = Vec2::new(x, y);
     }
 
-    pub fn add_entity(&mut self, entity: EntityRef) {
-        self.entities.push(entity)
+    /// Makes `advance` step in fixed increments of `delta` rather than by the frame time, so runs with
+    /// the same inputs produce the same states however fast they are rendered. `None` goes back to
+    /// stepping by frame time.
+    pub fn set_fixed_timestep(&mut self, delta: Option<f32>) {
+        self.fixed_delta = delta.filter(|delta| *delta > 0.0);
+        self.accumulator = 0.0;
+    }
+
+    /// More iterations make joint chains stiffer and more accurate at the cost of step time.
+    pub fn set_solver_iterations(&mut self, iterations: usize) {
+        self.solver_iterations = iterations;
+    }
+
+    /// Moves the simulation forward by a frame of `frame_time` seconds, returning the number of steps taken.
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        let Some(delta) = self.fixed_delta else {
+            self.step(frame_time);
+            return 1;
+        };
+
+        self.accumulator += frame_time;
+        let mut steps = 0;
+        while self.accumulator >= delta && steps < MAX_SUBSTEPS {
+            self.step(delta);
+            self.accumulator -= delta;
+            steps += 1;
+        }
+
+        if steps == MAX_SUBSTEPS {
+            self.accumulator = self.accumulator.min(delta);
+        }
+        steps
+    }
+
+    /// FNV-1a hash over the exact bits of every body's state, plus the joint and effector handles.
+    /// Two worlds that went through the same steps hash the same.
+    pub fn state_hash(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        let mut write = |bits: u32| {
+            for byte in bits.to_le_bytes() {
+                hash = (hash ^ byte as u64).wrapping_mul(FNV_PRIME);
+            }
+        };
+
+        for (handle, entity) in &self.entities {
+            let entity = entity.borrow();
+            let (position, velocity) = (entity.get_position(), entity.get_velocity());
+
+            write(handle.0);
+            for value in [position.x, position.y, velocity.x, velocity.y, entity.get_angle(), entity.get_angular_velocity()] {
+                write(value.to_bits());
+            }
+        }
+
+        for handle in self.joints.keys() {
+            write(handle.0);
+        }
+
+        for slot in &self.effectors {
+            write(slot.handle.0);
+        }
+
+        hash
+    }
+
+    pub fn add_entity(&mut self, entity: EntityRef) -> EntityHandle {
+        let handle = EntityHandle(self.next_handle);
+        self.next_handle += 1;
+
+        self.entities.insert(handle, entity);
+        handle
+    }
+
+    pub fn get_entity(&self, handle: EntityHandle) -> Option<&EntityRef> {
+        self.entities.get(&handle)
+    }
+
+    /// Moves the pointer that mouse-following effectors steer by, including ones added later.
+    pub fn set_pointer(&mut self, pointer: Vec2) {
+        self.pointer = pointer;
+
+        for slot in &mut self.effectors {
+            slot.effector.set_pointer(pointer);
+        }
+    }
+
+    /// Adds an enabled effector with priority zero.
+    pub fn add_effector(&mut self, mut effector: Box<dyn Effector>) -> EffectorHandle {
+        effector.set_pointer(self.pointer);
+        let handle = EffectorHandle(self.next_effector_handle);
+        self.next_effector_handle += 1;
+
+        self.effectors.push(EffectorSlot {
+            handle,
+            effector,
+            enabled: true,
+            priority: 0,
+            time_to_live: None,
+        });
+        self.sort_effectors();
+        handle
+    }
+
+    pub fn get_effector_mut(&mut self, handle: EffectorHandle) -> Option<&mut Box<dyn Effector>> {
+        self.effector_slot_mut(handle).map(|slot| &mut slot.effector)
+    }
+
+    pub fn remove_effector(&mut self, handle: EffectorHandle) -> Option<Box<dyn Effector>> {
+        let index = self.effectors.iter().position(|slot| slot.handle == handle)?;
+        Some(self.effectors.remove(index).effector)
+    }
+
+    /// Disabled effectors are neither updated nor rendered, and their time to live is paused.
+    pub fn set_enabled(&mut self, handle: EffectorHandle, enabled: bool) {
+        if let Some(slot) = self.effector_slot_mut(handle) {
+            slot.enabled = enabled;
+        }
+    }
+
+    pub fn is_enabled(&self, handle: EffectorHandle) -> bool {
+        self.effectors.iter().any(|slot| slot.handle == handle && slot.enabled)
+    }
+
+    /// Removes the effector after it has been updated for `seconds`, `None` keeps it indefinitely.
+    pub fn set_time_to_live(&mut self, handle: EffectorHandle, seconds: Option<f32>) {
+        if let Some(slot) = self.effector_slot_mut(handle) {
+            slot.time_to_live = seconds;
+        }
+    }
+
+    /// Effectors run in ascending priority, ties in the order they were added.
+    pub fn set_priority(&mut self, handle: EffectorHandle, priority: i32) {
+        if let Some(slot) = self.effector_slot_mut(handle) {
+            slot.priority = priority;
+            self.sort_effectors();
+        }
+    }
+
+    /// Handle of the first effector of type `T`, in update order.
+    pub fn find_effector<T: Effector>(&self) -> Option<EffectorHandle> {
+        self.effectors.iter().find(|slot| slot.effector.as_any().is::<T>()).map(|slot| slot.handle)
+    }
+
+    fn effector_slot_mut(&mut self, handle: EffectorHandle) -> Option<&mut EffectorSlot> {
+        self.effectors.iter_mut().find(|slot| slot.handle == handle)
+    }
+
+    fn sort_effectors(&mut self) {
+        self.effectors.sort_by_key(|slot| (slot.priority, slot.handle));
+    }
+
+    /// Topmost entity whose collider contains `point`, if any.
+    pub fn query_point(&self, point: Vec2) -> Option<EntityHandle> {
+        self.entities.iter().rev()
+            .find(|(_, entity)| {
+                let entity = entity.borrow();
+                entity.get_collider().contains_point(&entity.get_position(), &point)
+            })
+            .map(|(handle, _)| *handle)
     }
 
-    pub fn add_effector(&mut self, effector: Box<dyn Effector>) {
-        self.effectors.push_back(effector);
+    pub fn add_joint(&mut self, joint: Box<dyn Joint>) -> JointHandle {
+        let handle = JointHandle(self.next_joint_handle);
+        self.next_joint_handle += 1;
+
+        self.joints.insert(handle, joint);
+        handle
+    }
+
+    pub fn get_joint_mut(&mut self, handle: JointHandle) -> Option<&mut Box<dyn Joint>> {
+        self.joints.get_mut(&handle)
+    }
+
+    pub fn remove_joint(&mut self, handle: JointHandle) -> Option<Box<dyn Joint>> {
+        self.joints.remove(&handle)
+    }
+
+    /// Total potential energy currently stored in the world's joints.
+    pub fn joint_energy(&self) -> f32 {
+        self.joints.values().map(|joint| joint.potential_energy(&self.entities)).sum()
     }
 
     pub fn update(&mut self, delta: f32) {
-        for effector in &mut self.effectors {
-            effector.update(delta);
+        for slot in self.effectors.iter_mut().filter(|slot| slot.enabled) {
+            let targets = slot.effector.get_selector().resolve(&self.entities);
+            slot.effector.update(&targets, &self.entities, delta);
+
+            if let Some(ttl) = &mut slot.time_to_live {
+                *ttl -= delta;
+            }
         }
+
+        self.effectors.retain(|slot| !slot.effector.is_expired() && slot.time_to_live.is_none_or(|ttl| ttl > 0.0));
     }
 
-    pub fn render_entities(&mut self) {
-        self.entities.retain(|x| {
+    /// Timings and counts from the last step, and from the last `render_entities`.
+    pub fn stats(&self) -> &StepStats {
+        &self.stats
+    }
+
+    pub fn step(&mut self, delta_time: f32) {
+        let mut stats = StepStats { render: self.stats.render, ..StepStats::default() };
+
+        let started = Instant::now();
+        self.entities.retain(|_, x| {
             let x = x.borrow();
             let pos = x.get_position();
 
@@ -57,32 +326,97 @@ impl World {
             (pos.x >= 0.0 && pos.x <= self.size.x) && (pos.y >= 0.0 && pos.y <= self.size.y)
         });
 
-        let delta_time = get_frame_time();
+        // Joints referencing removed entities are dropped along with them
+        self.joints.retain(|_, joint| joint.get_entities().iter().all(|h| self.entities.contains_key(h)));
+        stats.events = started.elapsed();
+
+        let started = Instant::now();
+        self.update(delta_time);
+        stats.effectors = started.elapsed();
+
+        let started = Instant::now();
+        for joint in self.joints.values_mut() {
+            joint.apply_forces(&self.entities, delta_time);
+        }
+        stats.solver = started.elapsed();
 
-        for entity in &mut self.entities {
+        let started = Instant::now();
+        for entity in self.entities.values() {
             entity.borrow_mut().update(delta_time);
         }
+        stats.integration = started.elapsed();
+
+        let started = Instant::now();
+        if delta_time > 0.0 {
+            for joint in self.joints.values_mut() {
+                joint.prepare(&self.entities, delta_time);
+            }
+
+            for _ in 0..self.solver_iterations {
+                for joint in self.joints.values_mut() {
+                    joint.solve_velocity(&self.entities);
+                }
+            }
+
+            if !self.joints.is_empty() {
+                stats.solver_iterations = self.solver_iterations;
+            }
+        }
+        stats.solver += started.elapsed();
+
+        self.collision_loss = 0.0;
+
+        // Every pair is tested, so the broad phase is only gathering the bodies
+        let started = Instant::now();
+        // Pairs are visited in handle order, keeping the order of floating point operations reproducible
+        let entities: Vec<&EntityRef> = self.entities.values().collect();
+        stats.broad_phase = started.elapsed();
 
-        for i in 0..self.entities.len() {
-            for j in (i + 1)..self.entities.len() {
-                let (obj1, obj2) = (self.entities[i].borrow_mut(), self.entities[j].borrow_mut());
+        // Contacts are resolved as soon as they are found, their response counts as solver time
+        let started = Instant::now();
+        let mut response = Duration::ZERO;
+        for i in 0..entities.len() {
+            for j in (i + 1)..entities.len() {
+                let (obj1, obj2) = (entities[i].borrow_mut(), entities[j].borrow_mut());
                 let collision = obj1.get_collider().detect_collision(
                     &obj1.get_position(),
-                    &obj2.get_collider(),
+                    obj2.get_collider(),
                     &obj2.get_position()
                 );
 
 
                 if let Some(collision) = collision {
-                    handle_collision(obj1, obj2, &collision);
+                    let responding = Instant::now();
+                    self.collision_loss += handle_collision(obj1, obj2, &collision);
+                    response += responding.elapsed();
+                    stats.contacts += 1;
                 }
             }
         }
+        stats.narrow_phase = started.elapsed().saturating_sub(response);
+        stats.solver += response;
 
-        for entity in &self.entities {
-            entity.borrow_mut().render(self);
+        stats.bodies = entities.len();
+        stats.pairs = entities.len() * entities.len().saturating_sub(1) / 2;
+        self.stats = stats;
+    }
+
+    pub fn render_entities(&mut self) {
+        let started = Instant::now();
+
+        for slot in self.effectors.iter().filter(|slot| slot.enabled) {
+            slot.effector.render();
+        }
+
+        for joint in self.joints.values() {
+            joint.render(&self.entities);
         }
 
-        draw_text(&format!("{} entities", self.entities.iter().count()), 20.0, 50.0, 30.0, WHITE);
+        for entity in self.entities.values() {
+            entity.borrow().render(self);
+        }
+
+        draw_text(&format!("{} entities", self.entities.len()), 20.0, 50.0, 30.0, WHITE);
+        self.stats.render = started.elapsed();
     }
-}
\ No newline at end of file
+}
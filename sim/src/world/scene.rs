@@ -0,0 +1,809 @@
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::rc::Rc;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use common::math::vectors::Vec2;
+use crate::collisions::colliders::CircleBB;
+use crate::collisions::BoundBox;
+use crate::effectors::forces::conveyor::Conveyor;
+use crate::effectors::forces::coulomb::Coulomb;
+use crate::effectors::forces::drag::{Drag, DragCoefficients};
+use crate::effectors::forces::explosion::{BlastFalloff, Explosion};
+use crate::effectors::forces::fluid_volume::{FluidRegion, FluidVolume};
+use crate::effectors::forces::magnetic_field::UniformMagneticField;
+use crate::effectors::forces::mouse_gravity::MouseGravity;
+use crate::effectors::forces::mutual_gravity::MutualGravity;
+use crate::effectors::forces::point_attractor::{AttractorSource, Falloff, PointAttractor};
+use crate::effectors::forces::push_zone::PushZone;
+use crate::effectors::forces::thruster::Thruster;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::effectors::forces::vortex::Vortex;
+use crate::effectors::forces::wind_field::{WindField, WindSource};
+use crate::effectors::selector::Selector;
+use crate::effectors::Effector;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::entity::{Entity, EntityType};
+use crate::joints::prismatic::PrismaticJoint;
+use crate::joints::revolute::RevoluteJoint;
+use crate::joints::spring::{SpringJoint, SpringMode};
+use crate::joints::weld::WeldJoint;
+use crate::joints::{Joint, Motor};
+use crate::world::{EntityHandle, World};
+
+/// Bumped whenever a change to the format would make older files load differently.
+pub const SCENE_VERSION: u32 = 1;
+
+pub(crate) fn invalid(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+/// Unlimited values are left out rather than written as infinity, which JSON has no literal for.
+fn finite(value: f32) -> Option<f32> {
+    value.is_finite().then_some(value)
+}
+
+/// Serializable description of a world. Joints and effectors refer to entities by their index in `entities`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct Scene {
+    pub version: u32,
+    pub settings: WorldSettings,
+    #[serde(default)]
+    pub entities: Vec<EntityDesc>,
+    #[serde(default)]
+    pub joints: Vec<JointDesc>,
+    #[serde(default)]
+    pub effectors: Vec<EffectorDesc>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct WorldSettings {
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum ShapeDesc {
+    Circle { radius: f32 },
+    Rectangle { width: f32, height: f32 },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(default)]
+pub struct MaterialDesc {
+    pub mass: f32,
+    pub gravity_scale: f32,
+    pub charge: f32,
+}
+
+impl Default for MaterialDesc {
+    fn default() -> Self {
+        Self { mass: 2.0, gravity_scale: 1.0, charge: 0.0 }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct EntityDesc {
+    pub shape: ShapeDesc,
+    /// Centre of a circle, top left corner of a rectangle.
+    pub position: Vec2,
+    #[serde(default)]
+    pub velocity: Vec2,
+    #[serde(default)]
+    pub angle: f32,
+    #[serde(default)]
+    pub angular_velocity: f32,
+    #[serde(default, rename = "static")]
+    pub is_static: bool,
+    #[serde(default)]
+    pub material: MaterialDesc,
+    #[serde(default = "default_groups")]
+    pub groups: u32,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub max_linear_speed: Option<f32>,
+    #[serde(default)]
+    pub max_force: Option<f32>,
+}
+
+fn default_groups() -> u32 {
+    1
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct MotorDesc {
+    pub speed: f32,
+    pub max_force: f32,
+}
+
+/// Anchors and axes are given in world space for the entities' poses in the scene.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum JointDesc {
+    Spring { a: usize, b: usize, rest_length: f32, stiffness: f32, damping: f32 },
+    StiffSpring { a: usize, b: usize, rest_length: f32, frequency: f32, damping_ratio: f32 },
+    Revolute {
+        a: usize,
+        b: usize,
+        anchor: Vec2,
+        /// Where the joint holds `b` relative to `a`, taken from the bodies' poses when left out.
+        #[serde(default)]
+        pose: Option<JointPose>,
+        #[serde(default)]
+        limits: Option<(f32, f32)>,
+        #[serde(default)]
+        motor: Option<MotorDesc>,
+    },
+    Prismatic {
+        a: usize,
+        b: usize,
+        anchor: Vec2,
+        axis: Vec2,
+        /// Where the joint holds `b` relative to `a`, taken from the bodies' poses when left out.
+        #[serde(default)]
+        pose: Option<JointPose>,
+        #[serde(default)]
+        limits: Option<(f32, f32)>,
+        #[serde(default)]
+        motor: Option<MotorDesc>,
+    },
+    Weld {
+        a: usize,
+        b: usize,
+        anchor: Vec2,
+        /// Where the joint holds `b` relative to `a`, taken from the bodies' poses when left out.
+        #[serde(default)]
+        pose: Option<JointPose>,
+        /// Frequency and damping ratio of a soft weld.
+        #[serde(default)]
+        softness: Option<(f32, f32)>,
+    },
+}
+
+/// The anchor on `b` in its local frame and the relative angle the joint measures from, so a joint
+/// saved away from its rest pose keeps its limits and rest angle when loaded.
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct JointPose {
+    pub local_anchor_b: Vec2,
+    pub reference_angle: f32,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum SelectorDesc {
+    AllDynamic,
+    Groups(u32),
+    Tag(String),
+    Circle { center: Vec2, radius: f32 },
+    Rectangle { min: Vec2, max: Vec2 },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct EffectorDesc {
+    pub effector: EffectorKind,
+    /// Falls back to the effector's own default selector.
+    #[serde(default)]
+    pub selector: Option<SelectorDesc>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub priority: i32,
+    #[serde(default)]
+    pub time_to_live: Option<f32>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum WindDesc {
+    Uniform(Vec2),
+    Noise { base: Vec2, amplitude: f32, scale: f32, speed: f32 },
+    Grid { origin: Vec2, cell_size: f32, cols: usize, rows: usize, cells: Vec<Vec2> },
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum FluidRegionDesc {
+    Rectangle { min: Vec2, max: Vec2 },
+    Polygon(Vec<Vec2>),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum AttractorSourceDesc {
+    Fixed(Vec2),
+    Mouse,
+    Entity(usize),
+}
+
+/// Custom falloff closures cannot be written out, attractors using one are left out of saved scenes.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum FalloffDesc {
+    InverseSquare,
+    LinearSpring,
+    Constant,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum BlastFalloffDesc {
+    Constant,
+    Linear,
+    Quadratic,
+}
+
+/// Explosions are one-shot and never saved, but a scene can still start with one.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub enum EffectorKind {
+    UniformGravity { gravity: Vec2 },
+    MutualGravity { g: f32, softening: f32, theta: f32, threshold: usize },
+    MouseGravity { force: f32 },
+    Drag { linear: f32, quadratic: f32, angular: f32 },
+    Wind {
+        source: WindDesc,
+        linear: f32,
+        quadratic: f32,
+        /// Set when the scene also has a `Drag` with the same coefficients, which the wind then blows over.
+        #[serde(default)]
+        over_drag: bool,
+    },
+    Fluid {
+        region: FluidRegionDesc,
+        density: f32,
+        linear_drag: f32,
+        #[serde(default)]
+        angular_drag: Option<f32>,
+        /// Defaults to the top of the region.
+        #[serde(default)]
+        surface_level: Option<f32>,
+        gravity: Vec2,
+    },
+    PointAttractor {
+        source: AttractorSourceDesc,
+        falloff: FalloffDesc,
+        strength: f32,
+        #[serde(default)]
+        min_radius: f32,
+        #[serde(default)]
+        max_radius: Option<f32>,
+        #[serde(default)]
+        repel: bool,
+    },
+    Coulomb { k: f32, softening: f32 },
+    MagneticField { b: f32 },
+    Vortex { center: Vec2, strength: f32, pull: f32, radius: f32 },
+    PushZone { min: Vec2, max: Vec2, acceleration: Vec2 },
+    Conveyor { surface: usize, speed: f32 },
+    Thruster { entity: usize, offset: Vec2, direction: Vec2, max_thrust: f32 },
+    Explosion { center: Vec2, strength: f32, radius: f32, falloff: BlastFalloffDesc, occlusion: bool, lifetime: f32 },
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+/// Reads JSON when the file has a `.json` extension and RON otherwise.
+pub(crate) fn read_file<T: DeserializeOwned>(path: impl AsRef<Path>) -> io::Result<T> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path)?;
+
+    if is_json(path) {
+        serde_json::from_str(&text).map_err(|err| invalid(err.to_string()))
+    } else {
+        ron::from_str(&text).map_err(|err| invalid(err.to_string()))
+    }
+}
+
+pub(crate) fn write_file<T: Serialize>(value: &T, path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+
+    let text = if is_json(path) {
+        serde_json::to_string_pretty(value).map_err(|err| invalid(err.to_string()))?
+    } else {
+        ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()).map_err(|err| invalid(err.to_string()))?
+    };
+
+    fs::write(path, text)
+}
+
+impl Scene {
+    /// Reads a scene, as JSON when the file has a `.json` extension and as RON otherwise.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let scene: Scene = read_file(path)?;
+
+        if scene.version != SCENE_VERSION {
+            return Err(invalid(format!("unsupported scene version {}, expected {}", scene.version, SCENE_VERSION)));
+        }
+
+        Ok(scene)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        write_file(self, path)
+    }
+
+    /// Builds a fresh world, checking every entity reference along the way.
+    pub fn build(&self) -> io::Result<World> {
+        let mut world = World::new(self.settings.height, self.settings.width);
+
+        let handles: Vec<EntityHandle> = self.entities.iter()
+            .map(|desc| world.add_entity(build_entity(desc)))
+            .collect();
+        let entity = |index: usize| handles.get(index).copied()
+            .ok_or_else(|| invalid(format!("scene refers to missing entity {}", index)));
+
+        for desc in &self.joints {
+            let joint: Box<dyn Joint> = match desc {
+                JointDesc::Spring { a, b, rest_length, stiffness, damping } => {
+                    Box::new(SpringJoint::new(entity(*a)?, entity(*b)?, *rest_length, *stiffness, *damping))
+                }
+                JointDesc::StiffSpring { a, b, rest_length, frequency, damping_ratio } => {
+                    Box::new(SpringJoint::new_stiff(entity(*a)?, entity(*b)?, *rest_length, *frequency, *damping_ratio))
+                }
+                JointDesc::Revolute { a, b, anchor, pose, limits, motor } => {
+                    let mut joint = RevoluteJoint::new(&world, entity(*a)?, entity(*b)?, *anchor);
+                    if let Some(pose) = pose {
+                        joint.local_anchor_b = pose.local_anchor_b;
+                        joint.reference_angle = pose.reference_angle;
+                    }
+                    joint.limits = *limits;
+                    joint.motor = motor.as_ref().map(|m| Motor { speed: m.speed, max_force: m.max_force });
+                    Box::new(joint)
+                }
+                JointDesc::Prismatic { a, b, anchor, axis, pose, limits, motor } => {
+                    let mut joint = PrismaticJoint::new(&world, entity(*a)?, entity(*b)?, *anchor, *axis);
+                    if let Some(pose) = pose {
+                        joint.local_anchor_b = pose.local_anchor_b;
+                        joint.reference_angle = pose.reference_angle;
+                    }
+                    joint.limits = *limits;
+                    joint.motor = motor.as_ref().map(|m| Motor { speed: m.speed, max_force: m.max_force });
+                    Box::new(joint)
+                }
+                JointDesc::Weld { a, b, anchor, pose, softness } => {
+                    let mut joint = WeldJoint::new(&world, entity(*a)?, entity(*b)?, *anchor);
+                    if let Some(pose) = pose {
+                        joint.local_anchor_b = pose.local_anchor_b;
+                        joint.reference_angle = pose.reference_angle;
+                    }
+                    match softness {
+                        Some((frequency, damping_ratio)) => Box::new(joint.with_softness(*frequency, *damping_ratio)),
+                        None => Box::new(joint),
+                    }
+                }
+            };
+
+            world.add_joint(joint);
+        }
+
+        for desc in &self.effectors {
+            let mut effector = build_effector(&desc.effector, &entity)?;
+            if let Some(selector) = &desc.selector {
+                *effector.get_selector_mut() = build_selector(selector);
+            }
+
+            let handle = world.add_effector(effector);
+            world.set_enabled(handle, desc.enabled);
+            world.set_priority(handle, desc.priority);
+            world.set_time_to_live(handle, desc.time_to_live);
+        }
+
+        Ok(world)
+    }
+
+    /// Describes the world's current state. Mouse joints and explosions are transient and left out,
+    /// as are entities of other shapes and anything referring to them.
+    pub fn describe(world: &World) -> Self {
+        let mut indices = vec![];
+        let mut entities = vec![];
+
+        for (handle, entity) in &world.entities {
+            if let Some(desc) = describe_entity(&*entity.borrow()) {
+                indices.push(*handle);
+                entities.push(desc);
+            }
+        }
+
+        let index = |handle: EntityHandle| indices.iter().position(|h| *h == handle);
+
+        let joints = world.joints.values()
+            .filter_map(|joint| describe_joint(joint.as_ref(), world, &index))
+            .collect();
+
+        let effectors = world.effectors.iter()
+            .filter_map(|slot| {
+                Some(EffectorDesc {
+                    effector: describe_effector(slot.effector.as_ref(), &index)?,
+                    selector: Some(describe_selector(slot.effector.get_selector())),
+                    enabled: slot.enabled,
+                    priority: slot.priority,
+                    time_to_live: slot.time_to_live,
+                })
+            })
+            .collect();
+
+        Scene {
+            version: SCENE_VERSION,
+            settings: WorldSettings { width: world.size.x, height: world.size.y },
+            entities,
+            joints,
+            effectors,
+        }
+    }
+}
+
+impl World {
+    pub fn load_scene(path: impl AsRef<Path>) -> io::Result<World> {
+        Scene::load(path)?.build()
+    }
+
+    pub fn save_scene(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        Scene::describe(self).save(path)
+    }
+}
+
+fn build_entity(desc: &EntityDesc) -> Rc<RefCell<dyn Entity>> {
+    let Vec2 { x, y } = desc.position;
+    let entity_type = if desc.is_static { EntityType::Static } else { EntityType::Dynamic };
+    let max_linear_speed = desc.max_linear_speed.unwrap_or(f32::INFINITY);
+    let max_force = desc.max_force.unwrap_or(f32::INFINITY);
+
+    match desc.shape {
+        ShapeDesc::Circle { radius } => {
+            let mut e = CircleEntity::new(x, y);
+            e.collider.bound_box = BoundBox::Circle(CircleBB { radius });
+            e.velocity = desc.velocity;
+            e.angle = desc.angle;
+            e.angular_velocity = desc.angular_velocity;
+            e.mass = desc.material.mass;
+            e.gravity_scale = desc.material.gravity_scale;
+            e.charge = desc.material.charge;
+            e.max_linear_speed = max_linear_speed;
+            e.max_force = max_force;
+            e.groups = desc.groups;
+            e.tags = desc.tags.clone();
+            e.entity_type = entity_type;
+            Rc::new(RefCell::new(e))
+        }
+        ShapeDesc::Rectangle { width, height } => {
+            let mut e = RectangleEntity::new(x, y, width, height);
+            e.velocity = desc.velocity;
+            e.angle = desc.angle;
+            e.angular_velocity = desc.angular_velocity;
+            e.mass = desc.material.mass;
+            e.gravity_scale = desc.material.gravity_scale;
+            e.charge = desc.material.charge;
+            e.max_linear_speed = max_linear_speed;
+            e.max_force = max_force;
+            e.groups = desc.groups;
+            e.tags = desc.tags.clone();
+            e.entity_type = entity_type;
+            Rc::new(RefCell::new(e))
+        }
+    }
+}
+
+fn describe_entity(entity: &dyn Entity) -> Option<EntityDesc> {
+    let collider = entity.get_collider();
+    if collider.rel_pos.length() > 0.0 {
+        return None;
+    }
+
+    let shape = match &collider.bound_box {
+        BoundBox::Circle(c) => ShapeDesc::Circle { radius: c.radius },
+        BoundBox::Rectangle(r) => ShapeDesc::Rectangle { width: r.width, height: r.height },
+    };
+
+    Some(EntityDesc {
+        shape,
+        position: entity.get_position(),
+        velocity: entity.get_velocity(),
+        angle: entity.get_angle(),
+        angular_velocity: entity.get_angular_velocity(),
+        is_static: matches!(entity.get_type(), EntityType::Static),
+        material: MaterialDesc {
+            mass: entity.get_mass(),
+            gravity_scale: entity.get_gravity_scale(),
+            charge: entity.get_charge(),
+        },
+        groups: entity.get_groups(),
+        tags: entity.get_tags().to_vec(),
+        max_linear_speed: finite(entity.get_max_linear_speed()),
+        max_force: finite(entity.get_max_force()),
+    })
+}
+
+/// World space position of a point given in `handle`'s local frame.
+fn to_world(world: &World, handle: EntityHandle, local: Vec2) -> Option<Vec2> {
+    let entity = world.get_entity(handle)?.borrow();
+    Some(entity.get_center() + local.rotate(entity.get_angle()))
+}
+
+fn describe_joint(joint: &dyn Joint, world: &World, index: &impl Fn(EntityHandle) -> Option<usize>) -> Option<JointDesc> {
+    let motor = |motor: &Option<Motor>| motor.as_ref().map(|m| MotorDesc { speed: m.speed, max_force: m.max_force });
+    let joint = joint.as_any();
+
+    if let Some(j) = joint.downcast_ref::<SpringJoint>() {
+        let (a, b) = (index(j.entity_a)?, index(j.entity_b)?);
+        return Some(match j.mode {
+            SpringMode::Force { stiffness, damping } => {
+                JointDesc::Spring { a, b, rest_length: j.rest_length, stiffness, damping }
+            }
+            SpringMode::Constraint { frequency, damping_ratio } => {
+                JointDesc::StiffSpring { a, b, rest_length: j.rest_length, frequency, damping_ratio }
+            }
+        });
+    }
+
+    if let Some(j) = joint.downcast_ref::<RevoluteJoint>() {
+        return Some(JointDesc::Revolute {
+            a: index(j.entity_a)?,
+            b: index(j.entity_b)?,
+            anchor: to_world(world, j.entity_a, j.local_anchor_a)?,
+            pose: Some(JointPose { local_anchor_b: j.local_anchor_b, reference_angle: j.reference_angle }),
+            limits: j.limits,
+            motor: motor(&j.motor),
+        });
+    }
+
+    if let Some(j) = joint.downcast_ref::<PrismaticJoint>() {
+        let angle = world.get_entity(j.entity_a)?.borrow().get_angle();
+        return Some(JointDesc::Prismatic {
+            a: index(j.entity_a)?,
+            b: index(j.entity_b)?,
+            anchor: to_world(world, j.entity_a, j.local_anchor_a)?,
+            pose: Some(JointPose { local_anchor_b: j.local_anchor_b, reference_angle: j.reference_angle }),
+            axis: j.local_axis.rotate(angle),
+            limits: j.limits,
+            motor: motor(&j.motor),
+        });
+    }
+
+    if let Some(j) = joint.downcast_ref::<WeldJoint>() {
+        return Some(JointDesc::Weld {
+            a: index(j.entity_a)?,
+            b: index(j.entity_b)?,
+            anchor: to_world(world, j.entity_a, j.local_anchor_a)?,
+            pose: Some(JointPose { local_anchor_b: j.local_anchor_b, reference_angle: j.reference_angle }),
+            softness: (j.frequency > 0.0).then_some((j.frequency, j.damping_ratio)),
+        });
+    }
+
+    None
+}
+
+fn build_selector(desc: &SelectorDesc) -> Selector {
+    match desc {
+        SelectorDesc::AllDynamic => Selector::AllDynamic,
+        SelectorDesc::Groups(mask) => Selector::Groups(*mask),
+        SelectorDesc::Tag(tag) => Selector::Tag(tag.clone()),
+        SelectorDesc::Circle { center, radius } => Selector::circle(*center, *radius),
+        SelectorDesc::Rectangle { min, max } => Selector::rectangle(*min, *max),
+    }
+}
+
+fn describe_selector(selector: &Selector) -> SelectorDesc {
+    match selector {
+        Selector::AllDynamic => SelectorDesc::AllDynamic,
+        Selector::Groups(mask) => SelectorDesc::Groups(*mask),
+        Selector::Tag(tag) => SelectorDesc::Tag(tag.clone()),
+        Selector::Region { collider, position } => {
+            let origin = *position + collider.rel_pos;
+
+            match &collider.bound_box {
+                BoundBox::Circle(c) => SelectorDesc::Circle { center: origin, radius: c.radius },
+                BoundBox::Rectangle(r) => SelectorDesc::Rectangle {
+                    min: origin,
+                    max: origin + Vec2::new(r.width, r.height),
+                },
+            }
+        }
+    }
+}
+
+fn build_effector(
+    desc: &EffectorKind,
+    entity: &impl Fn(usize) -> io::Result<EntityHandle>,
+) -> io::Result<Box<dyn Effector>> {
+    Ok(match desc {
+        EffectorKind::UniformGravity { gravity } => Box::new(UniformGravity::new(*gravity)),
+        EffectorKind::MutualGravity { g, softening, theta, threshold } => {
+            let mut gravity = MutualGravity::new(*g, *softening);
+            gravity.theta = *theta;
+            gravity.threshold = *threshold;
+            Box::new(gravity)
+        }
+        EffectorKind::MouseGravity { force } => Box::new(MouseGravity::new(*force)),
+        EffectorKind::Drag { linear, quadratic, angular } => Box::new(Drag::new(*linear, *quadratic, *angular)),
+        EffectorKind::Wind { source, linear, quadratic, over_drag } => {
+            let source = match source {
+                WindDesc::Uniform(v) => WindSource::Uniform(*v),
+                WindDesc::Noise { base, amplitude, scale, speed } => {
+                    WindSource::Noise { base: *base, amplitude: *amplitude, scale: *scale, speed: *speed }
+                }
+                WindDesc::Grid { origin, cell_size, cols, rows, cells } => {
+                    if cells.len() != cols * rows {
+                        return Err(invalid("wind grid has the wrong number of cells".to_string()));
+                    }
+                    WindSource::Grid {
+                        origin: *origin,
+                        cell_size: *cell_size,
+                        cols: *cols,
+                        rows: *rows,
+                        cells: cells.to_vec(),
+                    }
+                }
+            };
+            let mut wind = WindField::new(source, DragCoefficients { linear: *linear, quadratic: *quadratic });
+            wind.over_drag = *over_drag;
+            Box::new(wind)
+        }
+        EffectorKind::Fluid { region, density, linear_drag, angular_drag, surface_level, gravity } => {
+            let region = match region {
+                FluidRegionDesc::Rectangle { min, max } => FluidRegion::Rectangle { min: *min, max: *max },
+                FluidRegionDesc::Polygon(points) => FluidRegion::Polygon(points.to_vec()),
+            };
+            let mut fluid = FluidVolume::new(region, *density, *linear_drag, *gravity);
+            if let Some(angular_drag) = angular_drag {
+                fluid.angular_drag = *angular_drag;
+            }
+            if let Some(surface_level) = surface_level {
+                fluid.surface_level = *surface_level;
+            }
+            Box::new(fluid)
+        }
+        EffectorKind::PointAttractor { source, falloff, strength, min_radius, max_radius, repel } => {
+            let source = match source {
+                AttractorSourceDesc::Fixed(p) => AttractorSource::Fixed(*p),
+                AttractorSourceDesc::Mouse => AttractorSource::Mouse,
+                AttractorSourceDesc::Entity(index) => AttractorSource::Entity(entity(*index)?),
+            };
+            let falloff = match falloff {
+                FalloffDesc::InverseSquare => Falloff::InverseSquare,
+                FalloffDesc::LinearSpring => Falloff::LinearSpring,
+                FalloffDesc::Constant => Falloff::Constant,
+            };
+            Box::new(PointAttractor::new(source, falloff, *strength)
+                .with_radius(*min_radius, max_radius.unwrap_or(f32::INFINITY))
+                .with_repel(*repel))
+        }
+        EffectorKind::Coulomb { k, softening } => Box::new(Coulomb::new(*k, *softening)),
+        EffectorKind::MagneticField { b } => Box::new(UniformMagneticField::new(*b)),
+        EffectorKind::Vortex { center, strength, pull, radius } => {
+            Box::new(Vortex::new(*center, *strength, *radius).with_pull(*pull))
+        }
+        EffectorKind::PushZone { min, max, acceleration } => {
+            Box::new(PushZone::new(*min, *max, *acceleration))
+        }
+        EffectorKind::Conveyor { surface, speed } => Box::new(Conveyor::new(entity(*surface)?, *speed)),
+        EffectorKind::Thruster { entity: index, offset, direction, max_thrust } => {
+            Box::new(Thruster::new(entity(*index)?, *offset, *direction, *max_thrust))
+        }
+        EffectorKind::Explosion { center, strength, radius, falloff, occlusion, lifetime } => {
+            let falloff = match falloff {
+                BlastFalloffDesc::Constant => BlastFalloff::Constant,
+                BlastFalloffDesc::Linear => BlastFalloff::Linear,
+                BlastFalloffDesc::Quadratic => BlastFalloff::Quadratic,
+            };
+            Box::new(Explosion::new(*center, *strength, *radius)
+                .with_falloff(falloff)
+                .with_occlusion(*occlusion)
+                .with_lifetime(*lifetime))
+        }
+    })
+}
+
+fn describe_effector(effector: &dyn Effector, index: &impl Fn(EntityHandle) -> Option<usize>) -> Option<EffectorKind> {
+    let effector = effector.as_any();
+
+    if let Some(e) = effector.downcast_ref::<UniformGravity>() {
+        return Some(EffectorKind::UniformGravity { gravity: e.gravity });
+    }
+
+    if let Some(e) = effector.downcast_ref::<MutualGravity>() {
+        return Some(EffectorKind::MutualGravity { g: e.g, softening: e.softening, theta: e.theta, threshold: e.threshold });
+    }
+
+    if let Some(e) = effector.downcast_ref::<MouseGravity>() {
+        return Some(EffectorKind::MouseGravity { force: e.force });
+    }
+
+    if let Some(e) = effector.downcast_ref::<Drag>() {
+        return Some(EffectorKind::Drag {
+            linear: e.coefficients.linear,
+            quadratic: e.coefficients.quadratic,
+            angular: e.angular,
+        });
+    }
+
+    if let Some(e) = effector.downcast_ref::<WindField>() {
+        let source = match &e.source {
+            WindSource::Uniform(v) => WindDesc::Uniform(*v),
+            WindSource::Noise { base, amplitude, scale, speed } => {
+                WindDesc::Noise { base: *base, amplitude: *amplitude, scale: *scale, speed: *speed }
+            }
+            WindSource::Grid { origin, cell_size, cols, rows, cells } => WindDesc::Grid {
+                origin: *origin,
+                cell_size: *cell_size,
+                cols: *cols,
+                rows: *rows,
+                cells: cells.to_vec(),
+            },
+        };
+        return Some(EffectorKind::Wind {
+            source,
+            linear: e.coefficients.linear,
+            quadratic: e.coefficients.quadratic,
+            over_drag: e.over_drag,
+        });
+    }
+
+    if let Some(e) = effector.downcast_ref::<FluidVolume>() {
+        let region = match &e.region {
+            FluidRegion::Rectangle { min, max } => FluidRegionDesc::Rectangle { min: *min, max: *max },
+            FluidRegion::Polygon(points) => FluidRegionDesc::Polygon(points.to_vec()),
+        };
+        return Some(EffectorKind::Fluid {
+            region,
+            density: e.density,
+            linear_drag: e.linear_drag,
+            angular_drag: Some(e.angular_drag),
+            surface_level: finite(e.surface_level),
+            gravity: e.gravity,
+        });
+    }
+
+    if let Some(e) = effector.downcast_ref::<PointAttractor>() {
+        let source = match &e.source {
+            AttractorSource::Fixed(p) => AttractorSourceDesc::Fixed(*p),
+            AttractorSource::Mouse => AttractorSourceDesc::Mouse,
+            AttractorSource::Entity(handle) => AttractorSourceDesc::Entity(index(*handle)?),
+        };
+        let falloff = match &e.falloff {
+            Falloff::InverseSquare => FalloffDesc::InverseSquare,
+            Falloff::LinearSpring => FalloffDesc::LinearSpring,
+            Falloff::Constant => FalloffDesc::Constant,
+            Falloff::Custom(_) => return None,
+        };
+        return Some(EffectorKind::PointAttractor {
+            source,
+            falloff,
+            strength: e.strength,
+            min_radius: e.min_radius,
+            max_radius: finite(e.max_radius),
+            repel: e.repel,
+        });
+    }
+
+    if let Some(e) = effector.downcast_ref::<Coulomb>() {
+        return Some(EffectorKind::Coulomb { k: e.k, softening: e.softening });
+    }
+
+    if let Some(e) = effector.downcast_ref::<UniformMagneticField>() {
+        return Some(EffectorKind::MagneticField { b: e.b });
+    }
+
+    if let Some(e) = effector.downcast_ref::<Vortex>() {
+        return Some(EffectorKind::Vortex { center: e.center, strength: e.strength, pull: e.pull, radius: e.radius });
+    }
+
+    if let Some(e) = effector.downcast_ref::<PushZone>() {
+        return Some(EffectorKind::PushZone { min: e.min, max: e.max, acceleration: e.acceleration });
+    }
+
+    if let Some(e) = effector.downcast_ref::<Conveyor>() {
+        return Some(EffectorKind::Conveyor { surface: index(e.surface)?, speed: e.speed });
+    }
+
+    if let Some(e) = effector.downcast_ref::<Thruster>() {
+        return Some(EffectorKind::Thruster {
+            entity: index(e.entity)?,
+            offset: e.offset,
+            direction: e.direction,
+            max_thrust: e.max_thrust,
+        });
+    }
+
+    None
+}
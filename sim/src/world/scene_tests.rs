@@ -0,0 +1,144 @@
+use super::scene::{Scene, SCENE_VERSION};
+use super::World;
+use crate::collisions::BoundBox;
+use crate::effectors::forces::conveyor::Conveyor;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::entity::EntityType;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::joints::prismatic::PrismaticJoint;
+use crate::joints::revolute::RevoluteJoint;
+use crate::joints::weld::WeldJoint;
+use crate::world::{EntityHandle, JointHandle};
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::fs;
+use std::io;
+use std::rc::Rc;
+
+const SCENE: &str = r#"(
+    version: 1,
+    settings: (width: 800.0, height: 600.0),
+    entities: [
+        (shape: Rectangle(width: 800.0, height: 15.0), position: (x: 0.0, y: 585.0), static: true),
+        (shape: Circle(radius: 25.0), position: (x: 100.0, y: 100.0), material: (mass: 5.0), tags: ["ball"]),
+        (shape: Rectangle(width: 30.0, height: 30.0), position: (x: 200.0, y: 100.0), velocity: (x: 10.0, y: 0.0)),
+    ],
+    joints: [
+        Revolute(a: 1, b: 2, anchor: (x: 150.0, y: 110.0), limits: Some((-0.5, 0.5))),
+    ],
+    effectors: [
+        (effector: UniformGravity(gravity: (x: 0.0, y: 200.0)), enabled: false),
+        (effector: Conveyor(surface: 0, speed: 50.0), selector: Some(Tag("ball")), priority: 2),
+    ],
+)"#;
+
+fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("physics-playground-{}-{}", std::process::id(), name))
+}
+
+#[test]
+fn test_build_applies_descriptions_and_defaults() {
+    let scene: Scene = ron::from_str(SCENE).unwrap();
+    let world = scene.build().unwrap();
+
+    assert_eq!(world.entities.len(), 3);
+    assert!(matches!(world.get_entity(EntityHandle(0)).unwrap().borrow().get_type(), EntityType::Static));
+
+    let ball = world.get_entity(EntityHandle(1)).unwrap().borrow();
+    assert!(matches!(ball.get_collider().bound_box, BoundBox::Circle(ref c) if c.radius == 25.0));
+    assert_eq!(ball.get_mass(), 5.0);
+    assert_eq!(ball.get_gravity_scale(), 1.0);
+    assert_eq!(ball.get_groups(), 1);
+    assert_eq!(ball.get_max_linear_speed(), f32::INFINITY);
+
+    let joint = world.joints.values().next().unwrap().as_any().downcast_ref::<RevoluteJoint>().unwrap();
+    assert_eq!(joint.limits, Some((-0.5, 0.5)));
+    assert_eq!(joint.local_anchor_a, Vec2::new(50.0, 10.0));
+
+    let gravity = world.find_effector::<UniformGravity>().unwrap();
+    assert!(!world.is_enabled(gravity));
+    let conveyor = world.find_effector::<Conveyor>().unwrap();
+    assert!(world.is_enabled(conveyor));
+}
+
+#[test]
+fn test_saved_scene_loads_back_unchanged() {
+    let scene: Scene = ron::from_str(SCENE).unwrap();
+    let world = scene.build().unwrap();
+
+    for name in ["scene.ron", "scene.json"] {
+        let path = temp_path(name);
+        world.save_scene(&path).unwrap();
+        let loaded = World::load_scene(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(Scene::describe(&loaded), Scene::describe(&world));
+    }
+}
+
+/// Local anchor on `b` and reference angle of the revolute, prismatic or weld joint at `handle`.
+fn joint_pose(world: &World, handle: JointHandle) -> (Vec2, f32) {
+    let joint = world.joints[&handle].as_any();
+    if let Some(j) = joint.downcast_ref::<RevoluteJoint>() {
+        return (j.local_anchor_b, j.reference_angle);
+    }
+    if let Some(j) = joint.downcast_ref::<PrismaticJoint>() {
+        return (j.local_anchor_b, j.reference_angle);
+    }
+    let j = joint.downcast_ref::<WeldJoint>().unwrap();
+    (j.local_anchor_b, j.reference_angle)
+}
+
+#[test]
+fn test_joints_saved_away_from_rest_keep_their_pose() {
+    let mut world = World::new(1000.0, 1000.0);
+    let base = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(100.0, 500.0, 600.0, 20.0))));
+    let flipper = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(150.0, 450.0, 80.0, 10.0))));
+    let slider = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(400.0, 480.0))));
+    let welded = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(600.0, 480.0))));
+
+    let joints = [
+        world.add_joint(Box::new(RevoluteJoint::new(&world, base, flipper, Vec2::new(150.0, 455.0)).with_limits(-0.2, 0.2))),
+        world.add_joint(Box::new(PrismaticJoint::new(&world, base, slider, Vec2::new(400.0, 480.0), Vec2::new(1.0, 0.0)).with_limits(-50.0, 50.0))),
+        world.add_joint(Box::new(WeldJoint::new(&world, base, welded, Vec2::new(600.0, 480.0)))),
+    ];
+    let poses: Vec<_> = joints.iter().map(|handle| joint_pose(&world, *handle)).collect();
+
+    // Turn the flipper, slide the slider and bend the weld before saving
+    *world.get_entity(flipper).unwrap().borrow_mut().get_angle_mut() = 0.5;
+    world.get_entity(slider).unwrap().borrow_mut().get_position_mut().x += 40.0;
+    *world.get_entity(welded).unwrap().borrow_mut().get_angle_mut() = 0.3;
+
+    let path = temp_path("posed.ron");
+    world.save_scene(&path).unwrap();
+    let loaded = World::load_scene(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    let loaded_poses: Vec<_> = loaded.joints.keys().map(|handle| joint_pose(&loaded, *handle)).collect();
+    assert_eq!(loaded_poses, poses);
+}
+
+#[test]
+fn test_invalid_scenes_are_rejected() {
+    let mut scene: Scene = ron::from_str(SCENE).unwrap();
+    scene.entities.truncate(1);
+    assert_eq!(scene.build().err().unwrap().kind(), io::ErrorKind::InvalidData);
+
+    let path = temp_path("future.ron");
+    fs::write(&path, SCENE.replacen("version: 1", &format!("version: {}", SCENE_VERSION + 1), 1)).unwrap();
+    let result = World::load_scene(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(result.err().unwrap().kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_example_scene_builds() {
+    let scene: Scene = ron::from_str(include_str!("../../scenes/pendulum.ron")).unwrap();
+    let world = scene.build().unwrap();
+
+    assert_eq!(world.entities.len(), 7);
+    assert_eq!(world.joints.len(), 2);
+    assert!(world.find_effector::<UniformGravity>().is_some_and(|gravity| world.is_enabled(gravity)));
+}
@@ -0,0 +1,50 @@
+use common::math::vectors::Vec2;
+use crate::joints::Joint;
+use crate::world::{EffectorSlot, EntityHandle, EntityRef, JointHandle, World};
+
+/// Owned copy of a world's full state: bodies, effectors and joints including their internal and solver state,
+/// and the handle counters. Restoring it any number of times brings the world back to exactly this point.
+pub struct Snapshot {
+    size: Vec2,
+    next_handle: u32,
+    next_joint_handle: u32,
+    next_effector_handle: u32,
+    accumulator: f32,
+    collision_loss: f32,
+    pointer: Vec2,
+    entities: Vec<(EntityHandle, EntityRef)>,
+    effectors: Vec<EffectorSlot>,
+    joints: Vec<(JointHandle, Box<dyn Joint>)>,
+}
+
+impl World {
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            size: self.size,
+            next_handle: self.next_handle,
+            next_joint_handle: self.next_joint_handle,
+            next_effector_handle: self.next_effector_handle,
+            accumulator: self.accumulator,
+            collision_loss: self.collision_loss,
+            pointer: self.pointer,
+            entities: self.entities.iter().map(|(handle, e)| (*handle, e.borrow().clone_entity())).collect(),
+            effectors: self.effectors.clone(),
+            joints: self.joints.iter().map(|(handle, joint)| (*handle, joint.clone_box())).collect(),
+        }
+    }
+
+    /// Replaces the world's state with a copy of `snapshot`. Entities are recreated, so references
+    /// held from before the restore no longer belong to the world; handles stay valid.
+    pub fn restore(&mut self, snapshot: &Snapshot) {
+        self.size = snapshot.size;
+        self.next_handle = snapshot.next_handle;
+        self.next_joint_handle = snapshot.next_joint_handle;
+        self.next_effector_handle = snapshot.next_effector_handle;
+        self.accumulator = snapshot.accumulator;
+        self.collision_loss = snapshot.collision_loss;
+        self.pointer = snapshot.pointer;
+        self.entities = snapshot.entities.iter().map(|(handle, e)| (*handle, e.borrow().clone_entity())).collect();
+        self.effectors = snapshot.effectors.clone();
+        self.joints = snapshot.joints.iter().map(|(handle, joint)| (*handle, joint.clone_box())).collect();
+    }
+}
@@ -0,0 +1,98 @@
+use super::World;
+use crate::effectors::forces::drag::DragCoefficients;
+use crate::effectors::forces::explosion::Explosion;
+use crate::effectors::forces::uniform_gravity::UniformGravity;
+use crate::effectors::forces::wind_field::{WindField, WindSource};
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::joints::revolute::RevoluteJoint;
+use crate::joints::spring::SpringJoint;
+use common::math::vectors::Vec2;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const DELTA: f32 = 1.0 / 60.0;
+
+fn busy_world() -> World {
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 900.0, 1000.0, 20.0))));
+    let a = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(400.0, 400.0))));
+    let b = world.add_entity(Rc::new(RefCell::new(RectangleEntity::new(450.0, 380.0, 30.0, 30.0))));
+    let c = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(600.0, 300.0))));
+
+    world.add_joint(Box::new(RevoluteJoint::new(&world, a, b, Vec2::new(440.0, 400.0))));
+    world.add_joint(Box::new(SpringJoint::new_stiff(b, c, 100.0, 2.0, 0.3)));
+
+    world.add_effector(Box::new(UniformGravity::new(Vec2::new(0.0, 200.0))));
+    let noise = WindSource::Noise { base: Vec2::zeros(), amplitude: 200.0, scale: 0.01, speed: 0.5 };
+    world.add_effector(Box::new(WindField::new(noise, DragCoefficients { linear: 0.01, quadratic: 0.0 })));
+    world.add_effector(Box::new(Explosion::new(Vec2::new(420.0, 420.0), 300.0, 200.0).with_lifetime(0.5)));
+    world
+}
+
+/// Bit patterns of every body's pose and velocity, in handle order.
+fn state(world: &World) -> Vec<(u32, [u32; 6])> {
+    world.entities.iter()
+        .map(|(handle, e)| {
+            let e = e.borrow();
+            let (p, v) = (e.get_position(), e.get_velocity());
+            let bits = [p.x, p.y, v.x, v.y, e.get_angle(), e.get_angular_velocity()].map(f32::to_bits);
+            (handle.0, bits)
+        })
+        .collect()
+}
+
+fn run(world: &mut World, steps: usize) {
+    for _ in 0..steps {
+        world.step(DELTA);
+    }
+}
+
+#[test]
+fn test_restore_replays_bit_exactly() {
+    let mut world = busy_world();
+    run(&mut world, 10);
+
+    let snapshot = world.snapshot();
+    run(&mut world, 50);
+    let expected = state(&world);
+
+    world.restore(&snapshot);
+    run(&mut world, 50);
+    assert_eq!(state(&world), expected);
+
+    // The snapshot is left untouched and can be restored again
+    world.restore(&snapshot);
+    run(&mut world, 50);
+    assert_eq!(state(&world), expected);
+}
+
+#[test]
+fn test_restore_brings_back_removed_parts_and_handles() {
+    let mut world = busy_world();
+    let snapshot = world.snapshot();
+
+    run(&mut world, 60);
+    assert_eq!(world.effectors.len(), 2, "the explosion should have expired");
+
+    let late = world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    world.joints.clear();
+
+    world.restore(&snapshot);
+    assert_eq!(world.effectors.len(), 3);
+    assert_eq!(world.joints.len(), 2);
+    assert!(world.get_entity(late).is_none());
+    assert_eq!(world.add_entity(Rc::new(RefCell::new(CircleEntity::new(0.0, 0.0)))), late);
+}
+
+#[test]
+fn test_snapshot_is_detached_from_the_world() {
+    let mut world = busy_world();
+    let before = state(&world);
+    let snapshot = world.snapshot();
+
+    world.entities.values().for_each(|e| e.borrow_mut().get_position_mut().x += 1.0);
+    world.restore(&snapshot);
+
+    assert_eq!(state(&world), before);
+}
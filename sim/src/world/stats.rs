@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+/// Where the time of a step went, and how much work it had. Contact responses run as soon as a
+/// contact is found during the narrow phase, their time is counted under `solver` together with the joints.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct StepStats {
+    pub effectors: Duration,
+    pub integration: Duration,
+    /// Every pair is tested, so this only covers gathering the bodies.
+    pub broad_phase: Duration,
+    pub narrow_phase: Duration,
+    pub solver: Duration,
+    /// Removing entities that left the world and the joints attached to them.
+    pub events: Duration,
+    /// Drawing the world, timed by `World::render_entities` rather than by the step.
+    pub render: Duration,
+    pub bodies: usize,
+    pub pairs: usize,
+    pub contacts: usize,
+    /// Velocity iterations the joint solver ran, zero when there was nothing to solve.
+    pub solver_iterations: usize,
+}
+
+impl StepStats {
+    /// Phases in the order they run, with their names.
+    pub fn phases(&self) -> [(&'static str, Duration); 7] {
+        [
+            ("events", self.events),
+            ("effectors", self.effectors),
+            ("integration", self.integration),
+            ("broad phase", self.broad_phase),
+            ("narrow phase", self.narrow_phase),
+            ("solver", self.solver),
+            ("render", self.render),
+        ]
+    }
+
+    /// Time spent in the step itself, without rendering.
+    pub fn step_time(&self) -> Duration {
+        self.events + self.effectors + self.integration + self.broad_phase + self.narrow_phase + self.solver
+    }
+}
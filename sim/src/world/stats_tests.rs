@@ -0,0 +1,54 @@
+use super::World;
+use crate::entity::circle_entity::CircleEntity;
+use crate::entity::rectangle_entity::RectangleEntity;
+use crate::joints::spring::SpringJoint;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+const DELTA: f32 = 1.0 / 60.0;
+
+fn world() -> World {
+    let mut world = World::new(1000.0, 1000.0);
+    world.add_entity(Rc::new(RefCell::new(RectangleEntity::new_static(0.0, 900.0, 1000.0, 20.0))));
+    world.add_entity(Rc::new(RefCell::new(CircleEntity::new(100.0, 100.0))));
+    world.add_entity(Rc::new(RefCell::new(CircleEntity::new(110.0, 100.0))));
+    world.add_entity(Rc::new(RefCell::new(CircleEntity::new(500.0, 500.0))));
+    world
+}
+
+#[test]
+fn test_step_counts_bodies_pairs_and_contacts() {
+    let mut world = world();
+    world.step(DELTA);
+
+    let stats = world.stats();
+    assert_eq!(stats.bodies, 4);
+    assert_eq!(stats.pairs, 6);
+    assert_eq!(stats.contacts, 1);
+    assert_eq!(stats.solver_iterations, 0);
+}
+
+#[test]
+fn test_solver_iterations_are_counted_when_there_are_joints() {
+    let mut world = world();
+    let (a, b) = (world.entities.keys().nth(1).copied().unwrap(), world.entities.keys().nth(3).copied().unwrap());
+    world.add_joint(Box::new(SpringJoint::new_stiff(a, b, 100.0, 2.0, 0.3)));
+    world.set_solver_iterations(4);
+    world.step(DELTA);
+
+    assert_eq!(world.stats().solver_iterations, 4);
+}
+
+#[test]
+fn test_contact_work_is_timed() {
+    let mut world = world();
+    world.step(DELTA);
+
+    let stats = world.stats();
+    assert_eq!(stats.contacts, 1);
+    assert!(stats.narrow_phase > Duration::ZERO);
+    assert!(stats.solver > Duration::ZERO);
+    assert!(stats.step_time() >= stats.narrow_phase + stats.solver);
+    assert_eq!(stats.render, Duration::ZERO, "stepping should not count as rendering");
+}